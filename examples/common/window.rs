@@ -8,14 +8,15 @@ use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, BorderType, Clear};
 use ratatui::widgets::Widget;
 
-use tachyonfx::{CellFilter, CellIterator, Effect, EffectTimer, IntoEffect, Shader};
+use tachyonfx::{Effect, IntoEffect, Shader};
 
-#[derive(Builder, Clone)]
+#[derive(Builder, Clone, Shader)]
 #[builder(pattern = "owned")]
+#[shader(process)]
 pub struct OpenWindow {
     title: Line<'static>,
-    #[builder(default, setter(strip_option))]
-    pre_render_fx: Option<Effect>, // for setting up geometry etc
+    #[shader(effect)]
+    pre_render_fx: Effect, // for setting up geometry etc
     #[builder(default, setter(strip_option))]
     parent_window_fx: Option<Effect>, // applied to whole buffer
     #[builder(default, setter(strip_option))]
@@ -43,7 +44,7 @@ impl OpenWindow {
         }
     }
 
-    fn window_block(&self) -> Block {
+    fn window_block(&self) -> Block<'_> {
         Block::new()
             .borders(Borders::ALL)
             .title_style(self.title_style)
@@ -53,16 +54,6 @@ impl OpenWindow {
             .style(self.background)
     }
 
-    pub fn processing_content_fx(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) {
-        if let Some(fx) = self.content_fx.as_mut() {
-            if fx.running() {
-                fx.process(duration, buf, area);
-            }
-        }
-    }
-}
-
-impl Shader for OpenWindow {
     fn process(
         &mut self,
         duration: Duration,
@@ -76,14 +67,12 @@ impl Shader for OpenWindow {
             }
         }
 
-        let overflow = match self.pre_render_fx.as_mut() {
-            Some(fx) if fx.running() => fx.process(duration, buf, area),
-            _                        => Some(duration)
+        let overflow = match self.pre_render_fx.running() {
+            true  => self.pre_render_fx.process(duration, buf, area),
+            false => Some(duration),
         };
 
-        let area = self.pre_render_fx.as_ref()
-            .map(Effect::area)
-            .flatten()
+        let area = self.pre_render_fx.area()
             .map(|area| area.clamp(buf.area))
             .unwrap_or(area);
 
@@ -97,41 +86,11 @@ impl Shader for OpenWindow {
         overflow
     }
 
-    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
-        // nothing to do
-    }
-
-
-    fn done(&self) -> bool {
-        self.pre_render_fx.is_none()
-            || self.pre_render_fx.as_ref().is_some_and(Effect::done)
-    }
-
-    fn clone_box(&self) -> Box<dyn Shader> {
-        Box::new(self.clone())
-    }
-
-    fn area(&self) -> Option<Rect> {
-        self.pre_render_fx.as_ref()
-            .map(Effect::area)
-            .unwrap_or(None)
-    }
-
-    fn set_area(&mut self, area: Rect) {
-        if let Some(open_window_fx) = self.pre_render_fx.as_mut() {
-            open_window_fx.set_area(area);
+    pub fn processing_content_fx(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) {
+        if let Some(fx) = self.content_fx.as_mut() {
+            if fx.running() {
+                fx.process(duration, buf, area);
+            }
         }
     }
-
-    fn set_cell_selection(&mut self, _strategy: CellFilter) {
-        todo!()
-    }
-
-    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
-        self.pre_render_fx.as_mut().and_then(Effect::timer_mut)
-    }
-
-    fn cell_selection(&self) -> Option<CellFilter> {
-        self.pre_render_fx.as_ref().map(Effect::cell_selection).flatten()
-    }
-}
\ No newline at end of file
+}
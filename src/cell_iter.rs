@@ -15,6 +15,8 @@ impl<'a> CellIterator<'a> {
         area: Rect,
         filter: Option<CellFilter>,
     ) -> Self {
+        // effects may be translated or resized beyond the edges of the buffer
+        let area = area.intersection(buf.area);
         Self { current: 0, area, buf, filter }
     }
     
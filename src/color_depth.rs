@@ -0,0 +1,287 @@
+use std::env;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier};
+
+use crate::palette::{ansi_color, global_palette, Palette};
+
+/// The global color depth, stored as the discriminant of a [ColorDepth].
+static GLOBAL_COLOR_DEPTH: AtomicU8 = AtomicU8::new(ColorDepth::TrueColor as u8);
+static GLOBAL_DITHERING: AtomicBool = AtomicBool::new(false);
+
+/// The colors a terminal is able to display.
+///
+/// Unless the [global color depth](global_color_depth) is `TrueColor`, the areas rendered
+/// by [process_effects](crate::EffectManager::process_effects) are quantized to colors the
+/// terminal can display. Effects rendered with
+/// [render_effect](crate::EffectRenderer::render_effect) are quantized by calling
+/// [quantize_to_global_depth] once all effects of the frame have been rendered.
+/// Alternatively, [fx::quantize_colors](crate::fx::quantize_colors) applies the
+/// quantization as an effect.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{set_global_color_depth, set_global_dithering, ColorDepth};
+///
+/// assert_eq!(ColorDepth::from_env(Some("truecolor"), Some("xterm")), ColorDepth::TrueColor);
+/// assert_eq!(ColorDepth::from_env(None, Some("tmux-256color")), ColorDepth::Ansi256);
+/// assert_eq!(ColorDepth::from_env(None, Some("xterm")), ColorDepth::Ansi16);
+/// assert_eq!(ColorDepth::from_env(None, Some("dumb")), ColorDepth::Mono);
+///
+/// set_global_color_depth(ColorDepth::detect());
+/// set_global_dithering(true);
+/// # set_global_color_depth(ColorDepth::TrueColor);
+/// # set_global_dithering(false);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorDepth {
+    /// 24-bit RGB colors.
+    #[default]
+    TrueColor,
+    /// The 256-color palette; RGB colors are mapped to the color cube and grayscale ramp.
+    Ansi256,
+    /// The 16 ANSI colors, as defined by the [global palette](crate::global_palette).
+    Ansi16,
+    /// The terminal's default colors only; cells with a background brighter than the
+    /// foreground are displayed in reverse video.
+    Mono,
+}
+
+impl ColorDepth {
+    /// Detects the color depth of the terminal from the `NO_COLOR`, `COLORTERM` and `TERM`
+    /// environment variables.
+    pub fn detect() -> Self {
+        if env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return ColorDepth::Mono;
+        }
+
+        let colorterm = env::var("COLORTERM").ok();
+        let term = env::var("TERM").ok();
+        Self::from_env(colorterm.as_deref(), term.as_deref())
+    }
+
+    /// Determines the color depth from the values of the `COLORTERM` and `TERM`
+    /// environment variables.
+    ///
+    /// # Arguments
+    /// * `colorterm` - The value of `COLORTERM`, if set.
+    /// * `term` - The value of `TERM`, if set.
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return ColorDepth::TrueColor;
+        }
+
+        let is_truecolor = |t: &str| t.contains("direct") || t.contains("truecolor");
+        match term.unwrap_or_default() {
+            "" | "dumb"                => ColorDepth::Mono,
+            t if is_truecolor(t)       => ColorDepth::TrueColor,
+            t if t.contains("256")     => ColorDepth::Ansi256,
+            _                          => ColorDepth::Ansi16,
+        }
+    }
+}
+
+/// Sets the global color depth, quantizing the output of all effects to colors the
+/// terminal can display.
+///
+/// # Arguments
+/// * `color_depth` - The color depth of the terminal.
+pub fn set_global_color_depth(color_depth: ColorDepth) {
+    GLOBAL_COLOR_DEPTH.store(color_depth as u8, Ordering::Relaxed);
+}
+
+/// Returns the global color depth.
+pub fn global_color_depth() -> ColorDepth {
+    match GLOBAL_COLOR_DEPTH.load(Ordering::Relaxed) {
+        0 => ColorDepth::TrueColor,
+        1 => ColorDepth::Ansi256,
+        2 => ColorDepth::Ansi16,
+        _ => ColorDepth::Mono,
+    }
+}
+
+/// Enables or disables ordered dithering when quantizing to the global color depth,
+/// which reduces the banding of gradients at the cost of a grainy texture.
+pub fn set_global_dithering(enabled: bool) {
+    GLOBAL_DITHERING.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true if dithering is enabled for the global color depth.
+pub fn global_dithering() -> bool {
+    GLOBAL_DITHERING.load(Ordering::Relaxed)
+}
+
+/// Quantizes the colors within `area` to the global color depth, if it isn't `TrueColor`.
+/// Effects read the colors of the buffer, so the quantization is a final pass: call it once
+/// per frame, after the last effect has been rendered, over the area covered by the effects.
+///
+/// # Arguments
+/// * `buf` - The buffer the effects were rendered to.
+/// * `area` - The area to quantize.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, quantize_to_global_depth, set_global_color_depth, ColorDepth, EffectRenderer};
+///
+/// set_global_color_depth(ColorDepth::Ansi256);
+///
+/// let area = Rect::new(0, 0, 10, 2);
+/// let mut buf = Buffer::empty(area);
+/// let mut fade = fx::fade_to_fg(Color::Rgb(250, 120, 30), 500);
+/// let mut sweep = fx::sweep_in(fx::Direction::LeftToRight, 4, Color::Black, 500);
+///
+/// buf.render_effect(&mut fade, area, Duration::from_millis(100));
+/// buf.render_effect(&mut sweep, area, Duration::from_millis(100));
+/// quantize_to_global_depth(&mut buf, area);
+/// assert!(matches!(buf.get(0, 0).fg, Color::Indexed(_)));
+/// # set_global_color_depth(ColorDepth::TrueColor);
+/// ```
+pub fn quantize_to_global_depth(buf: &mut Buffer, area: Rect) {
+    quantize(buf, area, global_color_depth(), global_dithering());
+}
+
+/// Replaces the colors within `area` which can't be displayed at the color depth. Colors
+/// which can be displayed are left as is, so quantizing is idempotent.
+pub(crate) fn quantize(buf: &mut Buffer, area: Rect, depth: ColorDepth, dither: bool) {
+    if depth == ColorDepth::TrueColor {
+        return;
+    }
+
+    let palette = global_palette();
+    let area = area.intersection(buf.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let offset = if dither { dither_offset(x, y) } else { 0.0 };
+            quantize_cell(buf.get_mut(x, y), depth, offset, &palette);
+        }
+    }
+}
+
+fn quantize_cell(cell: &mut Cell, depth: ColorDepth, offset: f32, palette: &Palette) {
+    match depth {
+        ColorDepth::TrueColor => {},
+        ColorDepth::Ansi256 => {
+            if let Color::Rgb(r, g, b) = cell.fg {
+                cell.set_fg(nearest_256(dither((r, g, b), offset, 40.0)));
+            }
+            if let Color::Rgb(r, g, b) = cell.bg {
+                cell.set_bg(nearest_256(dither((r, g, b), offset, 40.0)));
+            }
+        },
+        ColorDepth::Ansi16 => {
+            if !is_ansi16(cell.fg) {
+                let rgb = rgb(palette.resolve_fg(cell.fg));
+                cell.set_fg(nearest_16(dither(rgb, offset, 64.0), palette));
+            }
+            if !is_ansi16(cell.bg) {
+                let rgb = rgb(palette.resolve_bg(cell.bg));
+                cell.set_bg(nearest_16(dither(rgb, offset, 64.0), palette));
+            }
+        },
+        ColorDepth::Mono => {
+            if cell.fg == Color::Reset && cell.bg == Color::Reset {
+                return;
+            }
+
+            let fg = luminance(rgb(palette.resolve_fg(cell.fg)));
+            let bg = luminance(rgb(palette.resolve_bg(cell.bg)));
+            cell.set_fg(Color::Reset);
+            cell.set_bg(Color::Reset);
+            if bg > fg + offset * 64.0 {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+        },
+    }
+}
+
+fn is_ansi16(color: Color) -> bool {
+    match color {
+        Color::Rgb(..)      => false,
+        Color::Indexed(idx) => idx < 16,
+        _                   => true,
+    }
+}
+
+/// Returns the threshold of the 4x4 Bayer matrix at the cell, in the range `-0.5..0.5`.
+fn dither_offset(x: u16, y: u16) -> f32 {
+    const BAYER: [[u8; 4]; 4] = [
+        [ 0,  8,  2, 10],
+        [12,  4, 14,  6],
+        [ 3, 11,  1,  9],
+        [15,  7, 13,  5],
+    ];
+
+    let threshold = BAYER[y as usize % 4][x as usize % 4] as f32;
+    (threshold + 0.5) / 16.0 - 0.5
+}
+
+fn dither(rgb: (u8, u8, u8), offset: f32, spread: f32) -> (u8, u8, u8) {
+    let d = |c: u8| (c as f32 + offset * spread).round().clamp(0.0, 255.0) as u8;
+    (d(rgb.0), d(rgb.1), d(rgb.2))
+}
+
+fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        _                   => (0, 0, 0),
+    }
+}
+
+fn luminance((r, g, b): (u8, u8, u8)) -> f32 {
+    0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32
+}
+
+/// A perceptually weighted ("redmean") distance between two colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> f32 {
+    let r_mean = (a.0 as f32 + b.0 as f32) / 2.0;
+    let (dr, dg, db) = (
+        a.0 as f32 - b.0 as f32,
+        a.1 as f32 - b.1 as f32,
+        a.2 as f32 - b.2 as f32,
+    );
+
+    (2.0 + r_mean / 256.0) * dr * dr + 4.0 * dg * dg + (2.0 + (255.0 - r_mean) / 256.0) * db * db
+}
+
+/// Returns the closest color of the 6x6x6 color cube or the grayscale ramp; the first
+/// 16 colors are skipped, as they vary between terminals.
+fn nearest_256(rgb: (u8, u8, u8)) -> Color {
+    let palette = Palette::xterm();
+    let cube_idx = |c: u8| match c {
+        0..=47   => 0,
+        48..=114 => 1,
+        c        => (c - 35) / 40,
+    };
+
+    let (r, g, b) = (cube_idx(rgb.0), cube_idx(rgb.1), cube_idx(rgb.2));
+    let cube = 16 + 36 * r + 6 * g + b;
+
+    let average = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let gray = 232 + ((average.saturating_sub(3)) / 10).min(23) as u8;
+
+    let idx = match distance(rgb, palette.indexed(gray)) < distance(rgb, palette.indexed(cube)) {
+        true  => gray,
+        false => cube,
+    };
+
+    Color::Indexed(idx)
+}
+
+fn nearest_16(rgb: (u8, u8, u8), palette: &Palette) -> Color {
+    let idx = (0..16)
+        .min_by(|a, b| {
+            let da = distance(rgb, palette.indexed(*a));
+            let db = distance(rgb, palette.indexed(*b));
+            da.total_cmp(&db)
+        })
+        .unwrap();
+
+    ansi_color(idx)
+}
@@ -0,0 +1,210 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use ratatui::style::Color;
+
+use crate::interpolation::{HslConvertable, Interpolatable};
+use crate::palette::resolve;
+
+/// The global color space, stored as the discriminant of a [ColorSpace].
+static GLOBAL_COLOR_SPACE: AtomicU8 = AtomicU8::new(ColorSpace::Hsl as u8);
+
+/// The color space in which colors are interpolated, e.g. by fades and sweeps.
+///
+/// Effects use the [global color space](global_color_space) unless configured with
+/// [Effect::with_color_space](crate::Effect::with_color_space).
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::ColorSpace;
+///
+/// let red = Color::Rgb(255, 0, 0);
+/// let blue = Color::Rgb(0, 0, 255);
+///
+/// // the shortest arc from red to blue passes through magenta, not green
+/// assert_eq!(ColorSpace::Hsl.lerp(&red, &blue, 0.5), Color::Rgb(255, 0, 255));
+/// assert_eq!(ColorSpace::LinearRgb.lerp(&red, &blue, 0.5), Color::Rgb(188, 0, 188));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorSpace {
+    /// Linear-light RGB, i.e. sRGB with the gamma curve removed.
+    LinearRgb,
+    /// HSL, with the hue taking the shortest arc around the color wheel.
+    #[default]
+    Hsl,
+    /// The perceptually uniform OKLab color space.
+    Oklab,
+    /// The cylindrical form of OKLab, with the hue taking the shortest arc.
+    Oklch,
+}
+
+/// Sets the global color space, used by effects without a color space of their own
+/// and by the [Interpolatable] implementations of `Color` and `Style`.
+///
+/// # Arguments
+/// * `color_space` - The color space to interpolate colors in.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{global_color_space, set_global_color_space, ColorSpace};
+///
+/// set_global_color_space(ColorSpace::Oklab);
+/// assert_eq!(global_color_space(), ColorSpace::Oklab);
+/// set_global_color_space(ColorSpace::Hsl);
+/// ```
+pub fn set_global_color_space(color_space: ColorSpace) {
+    GLOBAL_COLOR_SPACE.store(color_space as u8, Ordering::Relaxed);
+}
+
+/// Returns the global color space.
+pub fn global_color_space() -> ColorSpace {
+    match GLOBAL_COLOR_SPACE.load(Ordering::Relaxed) {
+        0 => ColorSpace::LinearRgb,
+        1 => ColorSpace::Hsl,
+        2 => ColorSpace::Oklab,
+        _ => ColorSpace::Oklch,
+    }
+}
+
+impl ColorSpace {
+    /// Interpolates between two foreground colors in this color space. Colors other than
+    /// `Color::Rgb` are resolved with the [global palette](crate::global_palette), with
+    /// `Color::Reset` as the default foreground color.
+    ///
+    /// # Arguments
+    /// * `from` - The color at `alpha = 0.0`.
+    /// * `to` - The color at `alpha = 1.0`.
+    /// * `alpha` - The interpolation factor.
+    pub fn lerp(&self, from: &Color, to: &Color, alpha: f32) -> Color {
+        self.lerp_resolved(from, to, alpha, false)
+    }
+
+    /// Interpolates between two background colors in this color space; like
+    /// [lerp](ColorSpace::lerp), but with `Color::Reset` as the default background color.
+    pub fn lerp_bg(&self, from: &Color, to: &Color, alpha: f32) -> Color {
+        self.lerp_resolved(from, to, alpha, true)
+    }
+
+    fn lerp_resolved(&self, from: &Color, to: &Color, alpha: f32, bg: bool) -> Color {
+        if alpha == 0.0 {
+            return *from;
+        } else if alpha == 1.0 {
+            return *to;
+        }
+
+        let (from, to) = (&resolve(*from, bg), &resolve(*to, bg));
+
+        match self {
+            ColorSpace::LinearRgb => {
+                let (a, b) = (linear_rgb(from), linear_rgb(to));
+                from_linear_rgb(a.lerp(&b, alpha))
+            },
+            ColorSpace::Hsl => {
+                let (h1, s1, l1) = from.to_hsl();
+                let (h2, s2, l2) = to.to_hsl();
+                let (h1, h2) = achromatic_hues(h1, s1 == 0.0, h2, s2 == 0.0);
+
+                Color::from_hsl(
+                    lerp_hue(h1, h2, alpha) as f64,
+                    s1.lerp(&s2, alpha) as f64,
+                    l1.lerp(&l2, alpha) as f64,
+                )
+            },
+            ColorSpace::Oklab => {
+                let (a, b) = (oklab(from), oklab(to));
+                from_oklab(a.lerp(&b, alpha))
+            },
+            ColorSpace::Oklch => {
+                let [l1, c1, h1] = oklch(oklab(from));
+                let [l2, c2, h2] = oklch(oklab(to));
+                let (h1, h2) = achromatic_hues(h1, c1 < 1e-4, h2, c2 < 1e-4);
+
+                let lch = [l1.lerp(&l2, alpha), c1.lerp(&c2, alpha), lerp_hue(h1, h2, alpha)];
+                from_oklab(oklab_from_lch(lch))
+            },
+        }
+    }
+}
+
+/// Gives achromatic colors, which have no meaningful hue, the hue of the other color,
+/// so that e.g. fading from gray to red doesn't sweep through the color wheel.
+fn achromatic_hues(h1: f32, gray1: bool, h2: f32, gray2: bool) -> (f32, f32) {
+    match (gray1, gray2) {
+        (true, false) => (h2, h2),
+        (false, true) => (h1, h1),
+        _             => (h1, h2),
+    }
+}
+
+/// Interpolates between two hues, in degrees, along the shortest arc.
+fn lerp_hue(h1: f32, h2: f32, alpha: f32) -> f32 {
+    let delta = (h2 - h1 + 540.0) % 360.0 - 180.0;
+    (h1 + delta * alpha).rem_euclid(360.0)
+}
+
+fn rgb(color: &Color) -> [f32; 3] {
+    match color {
+        Color::Rgb(r, g, b) => [*r as f32 / 255.0, *g as f32 / 255.0, *b as f32 / 255.0],
+        _                   => [0.0; 3],
+    }
+}
+
+fn linear_rgb(color: &Color) -> [f32; 3] {
+    rgb(color).map(|c| match c {
+        c if c <= 0.04045 => c / 12.92,
+        c                 => ((c + 0.055) / 1.055).powf(2.4),
+    })
+}
+
+fn from_linear_rgb(rgb: [f32; 3]) -> Color {
+    let [r, g, b] = rgb.map(|c| {
+        let c = c.clamp(0.0, 1.0);
+        let c = match c {
+            c if c <= 0.0031308 => c * 12.92,
+            c                   => 1.055 * c.powf(1.0 / 2.4) - 0.055,
+        };
+        (c * 255.0).round() as u8
+    });
+
+    Color::Rgb(r, g, b)
+}
+
+fn oklab(color: &Color) -> [f32; 3] {
+    let [r, g, b] = linear_rgb(color);
+
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+fn from_oklab(lab: [f32; 3]) -> Color {
+    let [l, a, b] = lab;
+
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    from_linear_rgb([
+        4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_,
+        -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_,
+    ])
+}
+
+fn oklch(lab: [f32; 3]) -> [f32; 3] {
+    let [l, a, b] = lab;
+    [l, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0)]
+}
+
+fn oklab_from_lch(lch: [f32; 3]) -> [f32; 3] {
+    let [l, c, h] = lch;
+    let h = h.to_radians();
+    [l, c * h.cos(), c * h.sin()]
+}
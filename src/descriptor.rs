@@ -0,0 +1,386 @@
+//! Serializable descriptors for building effect trees from data.
+//!
+//! Descriptors mirror the constructors in [`fx`](crate::fx) and can be
+//! (de)serialized with any serde format, such as JSON or TOML. A descriptor
+//! is turned into a live [`Effect`] with [`EffectDescriptor::to_effect`].
+//!
+//! # Example
+//! ```
+//! use ratatui::style::Color;
+//! use tachyonfx::Interpolation::QuadOut;
+//! use tachyonfx::descriptor::{CellFilterDescriptor, EffectDescriptor, FxDescriptor, TimerDescriptor};
+//!
+//! let bg = Color::from_u32(0x1d2021);
+//! let descriptor: EffectDescriptor = FxDescriptor::Sequence {
+//!     effects: vec![
+//!         FxDescriptor::WithDuration {
+//!             duration_ms: 640,
+//!             effect: Box::new(FxDescriptor::NeverComplete {
+//!                 effect: Box::new(FxDescriptor::FadeTo { fg: bg, bg, timer: 0.into() }.into()),
+//!             }.into()),
+//!         }.into(),
+//!         FxDescriptor::FadeFrom { fg: bg, bg, timer: TimerDescriptor::new(640, QuadOut) }.into(),
+//!     ],
+//! }.into();
+//! let descriptor = descriptor.with_cell_filter(CellFilterDescriptor::Text);
+//!
+//! let json = serde_json::to_string(&descriptor).unwrap();
+//! let from_json: EffectDescriptor = serde_json::from_str(&json).unwrap();
+//! assert_eq!(descriptor, from_json);
+//!
+//! let toml = toml::to_string(&descriptor).unwrap();
+//! let from_toml: EffectDescriptor = toml::from_str(&toml).unwrap();
+//! assert_eq!(descriptor, from_toml);
+//!
+//! let effect = from_toml.to_effect();
+//! ```
+
+use std::time::Duration;
+
+use ratatui::layout::{Margin, Rect};
+use ratatui::style::{Color, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::color_space::ColorSpace;
+use crate::effect::{CellFilter, Effect};
+use crate::effect_timer::EffectTimer;
+use crate::fx;
+use crate::fx::{Direction, NoiseKind, RepeatMode, SweepShape};
+use crate::interpolation::Interpolation;
+use crate::pattern::Pattern;
+use crate::shader::Shader;
+
+/// Describes an effect, along with the optional cell filter and area
+/// it is applied to.
+///
+/// # Example
+/// ```
+/// use tachyonfx::descriptor::EffectDescriptor;
+///
+/// let json = r##"{
+///     "type": "parallel",
+///     "effects": [
+///         { "type": "coalesce", "cycle_len": 111, "timer": { "duration_ms": 440, "interpolation": "BounceOut" } },
+///         { "type": "fade_from", "fg": "#282828", "bg": "#282828", "timer": { "duration_ms": 640 } }
+///     ],
+///     "cell_filter": { "inner": [1, 1] }
+/// }"##;
+///
+/// let descriptor: EffectDescriptor = serde_json::from_str(json).unwrap();
+/// let effect = descriptor.to_effect();
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EffectDescriptor {
+    #[serde(flatten)]
+    pub fx: FxDescriptor,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cell_filter: Option<CellFilterDescriptor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area: Option<Rect>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_scale: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color_space: Option<ColorSpace>,
+}
+
+/// Describes the effect constructed by the corresponding function in [`fx`](crate::fx).
+///
+/// Not every function has a descriptor: effects built from [`Gradient`](crate::Gradient)s,
+/// [`Keyframes`](crate::Keyframes) or particle emitters, the transitions from a captured
+/// buffer, [`fx::quantize_colors`], [`fx::term256_colors`], [`fx::consume_tick`] and
+/// [`fx::timed_never_complete`] are only constructed in code.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FxDescriptor {
+    /// See [`fx::fade_to`].
+    FadeTo { fg: Color, bg: Color, timer: TimerDescriptor },
+    /// See [`fx::fade_from`].
+    FadeFrom { fg: Color, bg: Color, timer: TimerDescriptor },
+    /// See [`fx::fade_to_fg`].
+    FadeToFg { fg: Color, timer: TimerDescriptor },
+    /// See [`fx::fade_from_fg`].
+    FadeFromFg { fg: Color, timer: TimerDescriptor },
+    /// See [`fx::sweep_in`].
+    SweepIn { direction: Direction, gradient_length: u16, color: Color, timer: TimerDescriptor },
+    /// See [`fx::sweep_out`].
+    SweepOut { direction: Direction, gradient_length: u16, color: Color, timer: TimerDescriptor },
+    /// See [`fx::sweep_in_shape`].
+    SweepInShape { shape: SweepShape, gradient_length: u16, color: Color, timer: TimerDescriptor },
+    /// See [`fx::sweep_out_shape`].
+    SweepOutShape { shape: SweepShape, gradient_length: u16, color: Color, timer: TimerDescriptor },
+    /// See [`fx::dissolve`] and [`fx::dissolve_seeded`].
+    Dissolve {
+        cycle_len: usize,
+        timer: TimerDescriptor,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seed: Option<u64>,
+    },
+    /// See [`fx::coalesce`] and [`fx::coalesce_seeded`].
+    Coalesce {
+        cycle_len: usize,
+        timer: TimerDescriptor,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seed: Option<u64>,
+    },
+    /// See [`fx::typewriter`].
+    Typewriter { timer: TimerDescriptor },
+    /// See [`fx::hsl_shift`].
+    HslShift {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fg: Option<[f32; 3]>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bg: Option<[f32; 3]>,
+        timer: TimerDescriptor,
+    },
+    /// See [`fx::fire`], [`fx::plasma`] and [`fx::clouds`].
+    NoiseField { kind: NoiseKind, seed: u64, timer: TimerDescriptor },
+    /// See [`fx::explode`].
+    Explode { seed: u64, timer: TimerDescriptor },
+    /// See [`fx::assemble`].
+    Assemble { seed: u64, timer: TimerDescriptor },
+    /// See [`fx::matrix_rain`].
+    MatrixRain { seed: u64 },
+    /// See [`fx::slide_in`]; vacated cells are filled with the `fill` background color.
+    SlideIn { from: [i16; 2], fill: Color, timer: TimerDescriptor },
+    /// See [`fx::slide_out`]; vacated cells are filled with the `fill` background color.
+    SlideOut { to: [i16; 2], fill: Color, timer: TimerDescriptor },
+    /// See [`fx::translate`].
+    Translate {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effect: Option<Box<EffectDescriptor>>,
+        translate_by: [i16; 2],
+        timer: TimerDescriptor,
+    },
+    /// See [`fx::resize_area`].
+    ResizeArea {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effect: Option<Box<EffectDescriptor>>,
+        initial_w: u16,
+        initial_h: u16,
+        timer: TimerDescriptor,
+    },
+    /// See [`fx::sleep`].
+    Sleep { timer: TimerDescriptor },
+    /// See [`fx::sequence`].
+    Sequence { effects: Vec<EffectDescriptor> },
+    /// See [`fx::parallel`].
+    Parallel { effects: Vec<EffectDescriptor> },
+    /// See [`fx::repeat`].
+    Repeat { effect: Box<EffectDescriptor>, mode: RepeatModeDescriptor },
+    /// See [`fx::ping_pong`].
+    PingPong { effect: Box<EffectDescriptor> },
+    /// See [`fx::never_complete`].
+    NeverComplete { effect: Box<EffectDescriptor> },
+    /// See [`fx::with_duration`].
+    WithDuration { duration_ms: u32, effect: Box<EffectDescriptor> },
+    /// See [`fx::with_pattern`].
+    WithPattern { pattern: Pattern, effect: Box<EffectDescriptor> },
+}
+
+/// Describes an [`EffectTimer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimerDescriptor {
+    pub duration_ms: u32,
+    #[serde(default)]
+    pub interpolation: Interpolation,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reversed: bool,
+}
+
+/// Describes a [`RepeatMode`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepeatModeDescriptor {
+    Forever,
+    Times(u32),
+    DurationMs(u32),
+}
+
+/// Describes a [`CellFilter`]. Margins are given as `[horizontal, vertical]`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CellFilterDescriptor {
+    All,
+    FgColor(Color),
+    BgColor(Color),
+    Inner([u16; 2]),
+    Outer([u16; 2]),
+    Text,
+    Empty,
+    AllOf(Vec<CellFilterDescriptor>),
+    Not(Box<CellFilterDescriptor>),
+}
+
+impl EffectDescriptor {
+    /// Returns the descriptor with the specified cell filter.
+    pub fn with_cell_filter(self, cell_filter: CellFilterDescriptor) -> Self {
+        Self { cell_filter: Some(cell_filter), ..self }
+    }
+
+    /// Returns the descriptor with the specified area.
+    pub fn with_area(self, area: Rect) -> Self {
+        Self { area: Some(area), ..self }
+    }
+
+    /// Returns the descriptor with the specified time scale.
+    pub fn with_time_scale(self, time_scale: f32) -> Self {
+        Self { time_scale: Some(time_scale), ..self }
+    }
+
+    /// Returns the descriptor with the specified color space.
+    pub fn with_color_space(self, color_space: ColorSpace) -> Self {
+        Self { color_space: Some(color_space), ..self }
+    }
+
+    /// Constructs the live [`Effect`] described by this descriptor.
+    pub fn to_effect(&self) -> Effect {
+        let mut effect = self.fx.to_effect();
+        if let Some(filter) = self.cell_filter.as_ref() {
+            effect.set_cell_selection(filter.into());
+        }
+        if let Some(area) = self.area {
+            effect.set_area(area);
+        }
+        if let Some(time_scale) = self.time_scale {
+            effect.set_time_scale(time_scale);
+        }
+        if let Some(color_space) = self.color_space {
+            effect.set_color_space(color_space);
+        }
+
+        effect
+    }
+}
+
+impl FxDescriptor {
+    /// Constructs the live [`Effect`] described by this descriptor.
+    pub fn to_effect(&self) -> Effect {
+        let effects = |fxs: &[EffectDescriptor]| fxs.iter().map(EffectDescriptor::to_effect).collect();
+        let opt_effect = |fx: &Option<Box<EffectDescriptor>>| fx.as_ref().map(|fx| fx.to_effect());
+
+        match self {
+            FxDescriptor::FadeTo { fg, bg, timer }       => fx::fade_to(*fg, *bg, *timer),
+            FxDescriptor::FadeFrom { fg, bg, timer }     => fx::fade_from(*fg, *bg, *timer),
+            FxDescriptor::FadeToFg { fg, timer }         => fx::fade_to_fg(*fg, *timer),
+            FxDescriptor::FadeFromFg { fg, timer }       => fx::fade_from_fg(*fg, *timer),
+            FxDescriptor::SweepIn { direction, gradient_length, color, timer } =>
+                fx::sweep_in(*direction, *gradient_length, *color, *timer),
+            FxDescriptor::SweepOut { direction, gradient_length, color, timer } =>
+                fx::sweep_out(*direction, *gradient_length, *color, *timer),
+            FxDescriptor::SweepInShape { shape, gradient_length, color, timer } =>
+                fx::sweep_in_shape(*shape, *gradient_length, *color, *timer),
+            FxDescriptor::SweepOutShape { shape, gradient_length, color, timer } =>
+                fx::sweep_out_shape(*shape, *gradient_length, *color, *timer),
+            FxDescriptor::Dissolve { cycle_len, timer, seed: None }    => fx::dissolve(*cycle_len, *timer),
+            FxDescriptor::Dissolve { cycle_len, timer, seed: Some(s) } => fx::dissolve_seeded(*cycle_len, *s, *timer),
+            FxDescriptor::Coalesce { cycle_len, timer, seed: None }    => fx::coalesce(*cycle_len, *timer),
+            FxDescriptor::Coalesce { cycle_len, timer, seed: Some(s) } => fx::coalesce_seeded(*cycle_len, *s, *timer),
+            FxDescriptor::Typewriter { timer }           => fx::typewriter(*timer),
+            FxDescriptor::HslShift { fg, bg, timer }     => fx::hsl_shift(*fg, *bg, *timer),
+            FxDescriptor::NoiseField { kind, seed, timer } => match kind {
+                NoiseKind::Fire   => fx::fire(*seed, *timer),
+                NoiseKind::Plasma => fx::plasma(*seed, *timer),
+                NoiseKind::Clouds => fx::clouds(*seed, *timer),
+            },
+            FxDescriptor::Explode { seed, timer }        => fx::explode(*seed, *timer),
+            FxDescriptor::Assemble { seed, timer }       => fx::assemble(*seed, *timer),
+            FxDescriptor::MatrixRain { seed }            => fx::matrix_rain(*seed),
+            FxDescriptor::SlideIn { from: [x, y], fill, timer } =>
+                fx::slide_in((*x, *y), Style::default().bg(*fill), *timer),
+            FxDescriptor::SlideOut { to: [x, y], fill, timer } =>
+                fx::slide_out((*x, *y), Style::default().bg(*fill), *timer),
+            FxDescriptor::Translate { effect, translate_by: [x, y], timer } =>
+                fx::translate(opt_effect(effect), (*x, *y), *timer),
+            FxDescriptor::ResizeArea { effect, initial_w, initial_h, timer } =>
+                fx::resize_area(opt_effect(effect), *initial_w, *initial_h, *timer),
+            FxDescriptor::Sleep { timer }                => fx::sleep(*timer),
+            FxDescriptor::Sequence { effects: fxs }      => fx::sequence(effects(fxs)),
+            FxDescriptor::Parallel { effects: fxs }      => fx::parallel(effects(fxs)),
+            FxDescriptor::Repeat { effect, mode }        => fx::repeat(effect.to_effect(), (*mode).into()),
+            FxDescriptor::PingPong { effect }            => fx::ping_pong(effect.to_effect()),
+            FxDescriptor::NeverComplete { effect }       => fx::never_complete(effect.to_effect()),
+            FxDescriptor::WithDuration { duration_ms, effect } =>
+                fx::with_duration(Duration::from_millis(*duration_ms as u64), effect.to_effect()),
+            FxDescriptor::WithPattern { pattern, effect } => fx::with_pattern(*pattern, effect.to_effect()),
+        }
+    }
+}
+
+impl TimerDescriptor {
+    /// Creates a new `TimerDescriptor` with the specified duration in milliseconds
+    /// and interpolation method.
+    pub fn new(duration_ms: u32, interpolation: Interpolation) -> Self {
+        Self { duration_ms, interpolation, reversed: false }
+    }
+}
+
+impl From<FxDescriptor> for EffectDescriptor {
+    fn from(fx: FxDescriptor) -> Self {
+        Self { fx, cell_filter: None, area: None, time_scale: None, color_space: None }
+    }
+}
+
+impl From<EffectDescriptor> for Effect {
+    fn from(descriptor: EffectDescriptor) -> Self {
+        descriptor.to_effect()
+    }
+}
+
+impl From<FxDescriptor> for Effect {
+    fn from(descriptor: FxDescriptor) -> Self {
+        descriptor.to_effect()
+    }
+}
+
+impl From<u32> for TimerDescriptor {
+    fn from(duration_ms: u32) -> Self {
+        Self::new(duration_ms, Interpolation::Linear)
+    }
+}
+
+impl From<(u32, Interpolation)> for TimerDescriptor {
+    fn from((duration_ms, interpolation): (u32, Interpolation)) -> Self {
+        Self::new(duration_ms, interpolation)
+    }
+}
+
+impl From<TimerDescriptor> for EffectTimer {
+    fn from(descriptor: TimerDescriptor) -> Self {
+        let timer = EffectTimer::from_ms(descriptor.duration_ms, descriptor.interpolation);
+        if descriptor.reversed { timer.reversed() } else { timer }
+    }
+}
+
+impl From<RepeatModeDescriptor> for RepeatMode {
+    fn from(mode: RepeatModeDescriptor) -> Self {
+        match mode {
+            RepeatModeDescriptor::Forever        => RepeatMode::Forever,
+            RepeatModeDescriptor::Times(n)       => RepeatMode::Times(n),
+            RepeatModeDescriptor::DurationMs(ms) => RepeatMode::Duration(Duration::from_millis(ms as u64)),
+        }
+    }
+}
+
+impl From<&CellFilterDescriptor> for CellFilter {
+    fn from(filter: &CellFilterDescriptor) -> Self {
+        let margin = |[h, v]: [u16; 2]| Margin::new(h, v);
+
+        match filter {
+            CellFilterDescriptor::All          => CellFilter::All,
+            CellFilterDescriptor::FgColor(c)   => CellFilter::FgColor(*c),
+            CellFilterDescriptor::BgColor(c)   => CellFilter::BgColor(*c),
+            CellFilterDescriptor::Inner(m)     => CellFilter::Inner(margin(*m)),
+            CellFilterDescriptor::Outer(m)     => CellFilter::Outer(margin(*m)),
+            CellFilterDescriptor::Text         => CellFilter::Text,
+            CellFilterDescriptor::Empty        => CellFilter::Empty,
+            CellFilterDescriptor::AllOf(fs)    => CellFilter::AllOf(fs.iter().map(CellFilter::from).collect()),
+            CellFilterDescriptor::Not(f)       => CellFilter::Not(Box::new(f.as_ref().into())),
+        }
+    }
+}
+
+impl From<CellFilterDescriptor> for CellFilter {
+    fn from(filter: CellFilterDescriptor) -> Self {
+        (&filter).into()
+    }
+}
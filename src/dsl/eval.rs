@@ -0,0 +1,420 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use ratatui::layout::{Margin, Rect};
+use ratatui::style::{Color, Style};
+
+use crate::dsl::{ParseError, Position};
+use crate::dsl::parser::{Expr, Node};
+use crate::color_depth::ColorDepth;
+use crate::color_space::ColorSpace;
+use crate::effect::{CellFilter, Effect};
+use crate::effect_timer::EffectTimer;
+use crate::fx;
+use crate::fx::{Direction, RepeatMode, SweepShape};
+use crate::interpolation::{Interpolation, ParseInterpolationError, StepPosition};
+use crate::pattern::Pattern;
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+/// Evaluates the node into an effect.
+pub(crate) fn effect(node: &Node) -> Result<Effect> {
+    match &node.expr {
+        Expr::Call { name, args }             => call(name, args, node.pos),
+        Expr::Method { receiver, name, args } => method(effect(receiver)?, name, args, node.pos),
+        expr                                  => Err(mismatch(node.pos, "effect", expr)),
+    }
+}
+
+fn call(name: &str, args: &[Node], pos: Position) -> Result<Effect> {
+    let effect = match name {
+        "sequence" => fx::sequence(effects(args)?),
+        "parallel" => fx::parallel(effects(args)?),
+        name => {
+            let args = Args::new(name, args, pos);
+            match name {
+                "fade_to"         => args.arity(3).and_then(|a| Ok(fx::fade_to(a.color(0)?, a.color(1)?, a.timer(2)?)))?,
+                "fade_from"       => args.arity(3).and_then(|a| Ok(fx::fade_from(a.color(0)?, a.color(1)?, a.timer(2)?)))?,
+                "fade_to_fg"      => args.arity(2).and_then(|a| Ok(fx::fade_to_fg(a.color(0)?, a.timer(1)?)))?,
+                "fade_from_fg"    => args.arity(2).and_then(|a| Ok(fx::fade_from_fg(a.color(0)?, a.timer(1)?)))?,
+                "sweep_in"        => args.arity(4).and_then(|a| Ok(fx::sweep_in(a.direction(0)?, a.int(1)?, a.color(2)?, a.timer(3)?)))?,
+                "sweep_out"       => args.arity(4).and_then(|a| Ok(fx::sweep_out(a.direction(0)?, a.int(1)?, a.color(2)?, a.timer(3)?)))?,
+                "sweep_in_shape"  => args.arity(4).and_then(|a| Ok(fx::sweep_in_shape(a.sweep_shape(0)?, a.int(1)?, a.color(2)?, a.timer(3)?)))?,
+                "sweep_out_shape" => args.arity(4).and_then(|a| Ok(fx::sweep_out_shape(a.sweep_shape(0)?, a.int(1)?, a.color(2)?, a.timer(3)?)))?,
+                "dissolve"        => args.arity(2).and_then(|a| Ok(fx::dissolve(a.int(0)?, a.timer(1)?)))?,
+                "coalesce"        => args.arity(2).and_then(|a| Ok(fx::coalesce(a.int(0)?, a.timer(1)?)))?,
+                "dissolve_seeded" => args.arity(3).and_then(|a| Ok(fx::dissolve_seeded(a.int(0)?, a.int(1)?, a.timer(2)?)))?,
+                "coalesce_seeded" => args.arity(3).and_then(|a| Ok(fx::coalesce_seeded(a.int(0)?, a.int(1)?, a.timer(2)?)))?,
+                "typewriter"      => args.arity(1).and_then(|a| Ok(fx::typewriter(a.timer(0)?)))?,
+                "hsl_shift"       => args.arity(3).and_then(|a| Ok(fx::hsl_shift(a.hsl(0)?, a.hsl(1)?, a.timer(2)?)))?,
+                "hsl_shift_fg"    => args.arity(2).and_then(|a| Ok(fx::hsl_shift_fg(a.floats(0)?, a.timer(1)?)))?,
+                "term256_colors"  => args.arity(0).map(|_| fx::term256_colors())?,
+                "quantize_colors" => args.arity(2).and_then(|a| Ok(fx::quantize_colors(a.color_depth(0)?, a.bool(1)?)))?,
+                "fire"            => args.arity(2).and_then(|a| Ok(fx::fire(a.int(0)?, a.timer(1)?)))?,
+                "plasma"          => args.arity(2).and_then(|a| Ok(fx::plasma(a.int(0)?, a.timer(1)?)))?,
+                "clouds"          => args.arity(2).and_then(|a| Ok(fx::clouds(a.int(0)?, a.timer(1)?)))?,
+                "explode"         => args.arity(2).and_then(|a| Ok(fx::explode(a.int(0)?, a.timer(1)?)))?,
+                "assemble"        => args.arity(2).and_then(|a| Ok(fx::assemble(a.int(0)?, a.timer(1)?)))?,
+                "matrix_rain"     => args.arity(1).and_then(|a| Ok(fx::matrix_rain(a.int(0)?)))?,
+                "repeat"          => args.arity(2).and_then(|a| Ok(fx::repeat(a.effect(0)?, a.repeat_mode(1)?)))?,
+                "repeating"       => args.arity(1).and_then(|a| Ok(fx::repeating(a.effect(0)?)))?,
+                "ping_pong"       => args.arity(1).and_then(|a| Ok(fx::ping_pong(a.effect(0)?)))?,
+                "translate"       => args.arity(3).and_then(|a| Ok(fx::translate(a.opt_effect(0)?, a.offset(1)?, a.timer(2)?)))?,
+                "slide_in"        => args.arity(3).and_then(|a| Ok(fx::slide_in(a.offset(0)?, Style::default().bg(a.color(1)?), a.timer(2)?)))?,
+                "slide_out"       => args.arity(3).and_then(|a| Ok(fx::slide_out(a.offset(0)?, Style::default().bg(a.color(1)?), a.timer(2)?)))?,
+                "resize_area"     => args.arity(4).and_then(|a| Ok(fx::resize_area(a.opt_effect(0)?, a.int(1)?, a.int(2)?, a.timer(3)?)))?,
+                "sleep"           => args.arity(1).and_then(|a| Ok(fx::sleep(a.timer(0)?)))?,
+                "consume_tick"    => args.arity(0).map(|_| fx::consume_tick())?,
+                "never_complete"  => args.arity(1).and_then(|a| Ok(fx::never_complete(a.effect(0)?)))?,
+                "with_duration"   => args.arity(2).and_then(|a| Ok(fx::with_duration(a.duration(0)?, a.effect(1)?)))?,
+                "timed_never_complete" => args.arity(2).and_then(|a| Ok(fx::timed_never_complete(a.duration(0)?, a.effect(1)?)))?,
+                _ => return Err(ParseError::new(pos, format!("unknown effect '{name}'"))),
+            }
+        }
+    };
+
+    Ok(effect)
+}
+
+fn method(effect: Effect, name: &str, args: &[Node], pos: Position) -> Result<Effect> {
+    let args = Args::new(name, args, pos);
+    match name {
+        "with_filter"     => args.arity(1).and_then(|a| Ok(effect.with_cell_selection(a.cell_filter(0)?))),
+        "with_area"       => args.arity(4).and_then(|a| Ok(effect.with_area(Rect::new(a.int(0)?, a.int(1)?, a.int(2)?, a.int(3)?)))),
+        "with_duration"   => args.arity(1).and_then(|a| Ok(fx::with_duration(a.duration(0)?, effect))),
+        "with_time_scale" => args.arity(1).and_then(|a| Ok(effect.with_time_scale(a.float(0)?))),
+        "with_pattern"    => args.arity(1).and_then(|a| Ok(fx::with_pattern(a.pattern(0)?, effect))),
+        "with_color_space" => args.arity(1).and_then(|a| Ok(effect.with_color_space(a.color_space(0)?))),
+        "reversed"        => args.arity(0).map(|_| effect.reversed()),
+        _                 => Err(ParseError::new(pos, format!("unknown method '{name}'"))),
+    }
+}
+
+/// Evaluates a pattern, e.g. `Radial(0.5, 0.5)` or `Noise(4, 7).with_transition(0.5)`.
+fn pattern(node: &Node) -> Result<Pattern> {
+    match &node.expr {
+        Expr::Method { receiver, name, args } if name == "with_transition" => {
+            let pattern = pattern(receiver)?;
+            Args::new(name, args, node.pos).arity(1)
+                .and_then(|a| Ok(pattern.with_transition(a.float(0)?)))
+        },
+        Expr::Call { name, args } => {
+            let args = Args::new(name, args, node.pos);
+            let center = |a: &Args| Ok((a.float(0)?, a.float(1)?));
+            match name.as_str() {
+                "Linear"       => args.arity(1).and_then(|a| Ok(Pattern::linear(a.float(0)?))),
+                "Radial"       => args.arity(2).and_then(|a| Ok(Pattern::radial(center(&a)?))),
+                "Diamond"      => args.arity(2).and_then(|a| Ok(Pattern::diamond(center(&a)?))),
+                "Checkerboard" => args.arity(1).and_then(|a| Ok(Pattern::checkerboard(a.int(0)?))),
+                "Blinds"       => args.arity(2).and_then(|a| Ok(Pattern::blinds(a.int(0)?, a.bool(1)?))),
+                "Noise"        => args.arity(2).and_then(|a| Ok(Pattern::noise(a.float(0)?, a.int(1)?))),
+                "Random"       => args.arity(1).and_then(|a| Ok(Pattern::random(a.int(0)?))),
+                _              => Err(ParseError::new(node.pos, format!("unknown pattern '{name}'"))),
+            }
+        },
+        expr => Err(mismatch(node.pos, "pattern", expr)),
+    }
+}
+
+fn effects(args: &[Node]) -> Result<Vec<Effect>> {
+    args.iter().map(effect).collect()
+}
+
+/// The arguments of a call, with accessors converting each argument to the
+/// expected type.
+struct Args<'a> {
+    name: &'a str,
+    args: &'a [Node],
+    pos: Position,
+}
+
+impl<'a> Args<'a> {
+    fn new(name: &'a str, args: &'a [Node], pos: Position) -> Self {
+        Self { name, args, pos }
+    }
+
+    fn arity(self, n: usize) -> Result<Self> {
+        if self.args.len() == n {
+            Ok(self)
+        } else {
+            let msg = format!("'{}' expects {n} argument(s), found {}", self.name, self.args.len());
+            Err(ParseError::new(self.pos, msg))
+        }
+    }
+
+    fn effect(&self, idx: usize) -> Result<Effect> {
+        effect(&self.args[idx])
+    }
+
+    fn opt_effect(&self, idx: usize) -> Result<Option<Effect>> {
+        match &self.args[idx].expr {
+            Expr::Ident(name) if name == "None" => Ok(None),
+            _                                   => self.effect(idx).map(Some),
+        }
+    }
+
+    fn timer(&self, idx: usize) -> Result<EffectTimer> {
+        timer(&self.args[idx])
+    }
+
+    fn duration(&self, idx: usize) -> Result<Duration> {
+        duration(&self.args[idx])
+    }
+
+    fn color(&self, idx: usize) -> Result<Color> {
+        color(&self.args[idx])
+    }
+
+    fn direction(&self, idx: usize) -> Result<Direction> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::Ident(name) => match name.as_str() {
+                "LeftToRight" => Ok(Direction::LeftToRight),
+                "RightToLeft" => Ok(Direction::RightToLeft),
+                "UpToDown"    => Ok(Direction::UpToDown),
+                "DownToUp"    => Ok(Direction::DownToUp),
+                _             => Err(ParseError::new(node.pos, format!("unknown direction '{name}'"))),
+            },
+            expr => Err(mismatch(node.pos, "direction", expr)),
+        }
+    }
+
+    fn sweep_shape(&self, idx: usize) -> Result<SweepShape> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::Call { name, args } => {
+                let args = Args::new(name, args, node.pos);
+                let center = |a: &Args| Ok((a.float(0)?, a.float(1)?));
+                match name.as_str() {
+                    "Linear"    => args.arity(1).and_then(|a| Ok(SweepShape::Linear { angle: a.float(0)? })),
+                    "IrisOpen"  => args.arity(2).and_then(|a| Ok(SweepShape::IrisOpen { center: center(&a)? })),
+                    "IrisClose" => args.arity(2).and_then(|a| Ok(SweepShape::IrisClose { center: center(&a)? })),
+                    "Clockwise" => args.arity(3)
+                        .and_then(|a| Ok(SweepShape::Clockwise { center: center(&a)?, start_angle: a.float(2)? })),
+                    "CounterClockwise" => args.arity(3)
+                        .and_then(|a| Ok(SweepShape::CounterClockwise { center: center(&a)?, start_angle: a.float(2)? })),
+                    _ => Err(ParseError::new(node.pos, format!("unknown sweep shape '{name}'"))),
+                }
+            },
+            expr => Err(mismatch(node.pos, "sweep shape", expr)),
+        }
+    }
+
+    fn pattern(&self, idx: usize) -> Result<Pattern> {
+        pattern(&self.args[idx])
+    }
+
+    fn color_space(&self, idx: usize) -> Result<ColorSpace> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::Ident(name) => match name.as_str() {
+                "LinearRgb" => Ok(ColorSpace::LinearRgb),
+                "Hsl"       => Ok(ColorSpace::Hsl),
+                "Oklab"     => Ok(ColorSpace::Oklab),
+                "Oklch"     => Ok(ColorSpace::Oklch),
+                _           => Err(ParseError::new(node.pos, format!("unknown color space '{name}'"))),
+            },
+            expr => Err(mismatch(node.pos, "color space", expr)),
+        }
+    }
+
+    fn color_depth(&self, idx: usize) -> Result<ColorDepth> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::Ident(name) => match name.as_str() {
+                "TrueColor" => Ok(ColorDepth::TrueColor),
+                "Ansi256"   => Ok(ColorDepth::Ansi256),
+                "Ansi16"    => Ok(ColorDepth::Ansi16),
+                "Mono"      => Ok(ColorDepth::Mono),
+                _           => Err(ParseError::new(node.pos, format!("unknown color depth '{name}'"))),
+            },
+            expr => Err(mismatch(node.pos, "color depth", expr)),
+        }
+    }
+
+    fn bool(&self, idx: usize) -> Result<bool> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::Ident(name) if name == "true"  => Ok(true),
+            Expr::Ident(name) if name == "false" => Ok(false),
+            expr => Err(mismatch(node.pos, "boolean", expr)),
+        }
+    }
+
+    fn int<T: TryFrom<i64>>(&self, idx: usize) -> Result<T> {
+        int(&self.args[idx])
+    }
+
+    fn float(&self, idx: usize) -> Result<f32> {
+        let node = &self.args[idx];
+        match node.expr {
+            Expr::Number(n) => Ok(n as f32),
+            ref expr        => Err(mismatch(node.pos, "number", expr)),
+        }
+    }
+
+    fn floats<const N: usize>(&self, idx: usize) -> Result<[f32; N]> {
+        floats(&self.args[idx])
+    }
+
+    /// An `[x, y]` offset in cells.
+    fn offset(&self, idx: usize) -> Result<(i16, i16)> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::List(values) if values.len() == 2 => Ok((int(&values[0])?, int(&values[1])?)),
+            Expr::List(values) => {
+                let msg = format!("expected list of 2 integers, found {} element(s)", values.len());
+                Err(ParseError::new(node.pos, msg))
+            },
+            expr => Err(mismatch(node.pos, "list", expr)),
+        }
+    }
+
+    fn hsl(&self, idx: usize) -> Result<Option<[f32; 3]>> {
+        match &self.args[idx].expr {
+            Expr::Ident(name) if name == "None" => Ok(None),
+            _                                   => self.floats(idx).map(Some),
+        }
+    }
+
+    fn cell_filter(&self, idx: usize) -> Result<CellFilter> {
+        cell_filter(&self.args[idx])
+    }
+
+    fn step_position(&self, idx: usize) -> Result<StepPosition> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::Ident(name) => name.parse()
+                .map_err(|e: ParseInterpolationError| ParseError::new(node.pos, e.to_string())),
+            expr => Err(mismatch(node.pos, "step position", expr)),
+        }
+    }
+
+    fn repeat_mode(&self, idx: usize) -> Result<RepeatMode> {
+        let node = &self.args[idx];
+        match &node.expr {
+            Expr::Ident(name) if name == "Forever" => Ok(RepeatMode::Forever),
+            Expr::Call { name, args } => {
+                let args = Args::new(name, args, node.pos);
+                match name.as_str() {
+                    "Times"    => args.arity(1).and_then(|a| Ok(RepeatMode::Times(a.int(0)?))),
+                    "Duration" => args.arity(1).and_then(|a| Ok(RepeatMode::Duration(a.duration(0)?))),
+                    _          => Err(ParseError::new(node.pos, format!("unknown repeat mode '{name}'"))),
+                }
+            },
+            expr => Err(mismatch(node.pos, "repeat mode", expr)),
+        }
+    }
+}
+
+fn timer(node: &Node) -> Result<EffectTimer> {
+    match &node.expr {
+        Expr::Timer(d, interp) => Ok(EffectTimer::new(*d, interpolation(interp)?)),
+        _                      => duration(node).map(EffectTimer::from),
+    }
+}
+
+/// Durations are given with a unit; plain numbers are interpreted as milliseconds.
+fn duration(node: &Node) -> Result<Duration> {
+    match &node.expr {
+        Expr::Duration(d) => Ok(*d),
+        Expr::Number(_)   => int(node).map(Duration::from_millis),
+        expr              => Err(mismatch(node.pos, "duration", expr)),
+    }
+}
+
+fn color(node: &Node) -> Result<Color> {
+    match &node.expr {
+        Expr::Color(c)    => Ok(*c),
+        Expr::Ident(name) => Color::from_str(name)
+            .map_err(|_| ParseError::new(node.pos, format!("unknown color '{name}'"))),
+        expr              => Err(mismatch(node.pos, "color", expr)),
+    }
+}
+
+fn int<T: TryFrom<i64>>(node: &Node) -> Result<T> {
+    match &node.expr {
+        Expr::Number(n) if n.fract() == 0.0 => T::try_from(*n as i64)
+            .map_err(|_| ParseError::new(node.pos, format!("number {n} is out of range"))),
+        Expr::Number(n) => Err(ParseError::new(node.pos, format!("expected integer, found {n}"))),
+        expr            => Err(mismatch(node.pos, "integer", expr)),
+    }
+}
+
+fn floats<const N: usize>(node: &Node) -> Result<[f32; N]> {
+    let values: Vec<f32> = match &node.expr {
+        Expr::List(values) if values.len() == N => values.iter()
+            .map(|v| match v.expr {
+                Expr::Number(n) => Ok(n as f32),
+                ref expr        => Err(mismatch(v.pos, "number", expr)),
+            })
+            .collect::<Result<_>>()?,
+        Expr::List(values) => {
+            let msg = format!("expected list of {N} numbers, found {} element(s)", values.len());
+            return Err(ParseError::new(node.pos, msg))
+        },
+        expr => return Err(mismatch(node.pos, "list", expr)),
+    };
+
+    Ok(values.try_into().unwrap())
+}
+
+fn cell_filter(node: &Node) -> Result<CellFilter> {
+    let unknown = |name: &str| ParseError::new(node.pos, format!("unknown cell filter '{name}'"));
+    match &node.expr {
+        Expr::Ident(name) => match name.as_str() {
+            "All"   => Ok(CellFilter::All),
+            "Text"  => Ok(CellFilter::Text),
+            "Empty" => Ok(CellFilter::Empty),
+            _       => Err(unknown(name)),
+        },
+        Expr::Call { name, args } if name == "AllOf" => {
+            args.iter().map(cell_filter).collect::<Result<_>>().map(CellFilter::AllOf)
+        },
+        Expr::Call { name, args } => {
+            let args = Args::new(name, args, node.pos);
+            match name.as_str() {
+                "FgColor" => args.arity(1).and_then(|a| Ok(CellFilter::FgColor(a.color(0)?))),
+                "BgColor" => args.arity(1).and_then(|a| Ok(CellFilter::BgColor(a.color(0)?))),
+                "Inner"   => args.arity(2).and_then(|a| Ok(CellFilter::Inner(Margin::new(a.int(0)?, a.int(1)?)))),
+                "Outer"   => args.arity(2).and_then(|a| Ok(CellFilter::Outer(Margin::new(a.int(0)?, a.int(1)?)))),
+                "Not"     => args.arity(1).and_then(|a| Ok(CellFilter::Not(a.cell_filter(0)?.into()))),
+                _         => Err(unknown(name)),
+            }
+        },
+        expr => Err(mismatch(node.pos, "cell filter", expr)),
+    }
+}
+
+fn interpolation(node: &Node) -> Result<Interpolation> {
+    match &node.expr {
+        Expr::Ident(name) => name.parse()
+            .map_err(|e: ParseInterpolationError| ParseError::new(node.pos, e.to_string())),
+        Expr::Call { name, args } => {
+            let args = Args::new(name, args, node.pos);
+            match name.as_str() {
+                "CubicBezier" => args.arity(4).and_then(|a| Ok(Interpolation::CubicBezier(a.float(0)?, a.float(1)?, a.float(2)?, a.float(3)?))),
+                "Steps" if args.args.len() == 1 => args.arity(1).and_then(|a| Ok(Interpolation::Steps(a.int(0)?, StepPosition::default()))),
+                "Steps"       => args.arity(2).and_then(|a| Ok(Interpolation::Steps(a.int(0)?, a.step_position(1)?))),
+                "Spring"      => args.arity(2).and_then(|a| Ok(Interpolation::Spring { stiffness: a.float(0)?, damping: a.float(1)? })),
+                _             => Err(ParseError::new(node.pos, format!("unknown interpolation '{name}'"))),
+            }
+        },
+        expr => Err(mismatch(node.pos, "interpolation", expr)),
+    }
+}
+
+fn mismatch(pos: Position, expected: &str, found: &Expr) -> ParseError {
+    let found = match found {
+        Expr::Call { name, .. }   => format!("call to '{name}'"),
+        Expr::Method { name, .. } => format!("call to '{name}'"),
+        Expr::List(_)             => "list".to_string(),
+        Expr::Ident(name)         => format!("'{name}'"),
+        Expr::Number(n)           => format!("number {n}"),
+        Expr::Duration(d)         => format!("duration {d:?}"),
+        Expr::Timer(d, _)         => format!("timer {d:?}"),
+        Expr::Color(c)            => format!("color {c}"),
+    };
+
+    ParseError::new(pos, format!("expected {expected}, found {found}"))
+}
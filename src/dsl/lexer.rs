@@ -0,0 +1,141 @@
+use std::iter::Peekable;
+use std::str::Chars;
+use std::time::Duration;
+
+use ratatui::style::Color;
+
+use crate::dsl::{ParseError, Position};
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    Ident(String),
+    Number(f64),
+    Duration(Duration),
+    Color(Color),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Dot,
+    Eof,
+}
+
+/// Splits the input into tokens, each tagged with the position it starts at.
+pub(crate) fn tokenize(input: &str) -> Result<Vec<(Token, Position)>, ParseError> {
+    Lexer::new(input).tokenize()
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    pos: Position,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { chars: input.chars().peekable(), pos: Position::new(1, 1) }
+    }
+
+    fn tokenize(mut self) -> Result<Vec<(Token, Position)>, ParseError> {
+        let mut tokens = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let pos = self.pos;
+            let token = match self.chars.peek().copied() {
+                None                 => Token::Eof,
+                Some('(')            => self.single(Token::LParen),
+                Some(')')            => self.single(Token::RParen),
+                Some('[')            => self.single(Token::LBracket),
+                Some(']')            => self.single(Token::RBracket),
+                Some(',')            => self.single(Token::Comma),
+                Some('.')            => self.single(Token::Dot),
+                Some('#')            => self.color()?,
+                Some(c) if c == '-' || c.is_ascii_digit() => self.number()?,
+                Some(c) if c.is_alphabetic() || c == '_' => Token::Ident(self.take_while(is_ident)),
+                Some(c)              => return Err(ParseError::new(pos, format!("unexpected character '{c}'"))),
+            };
+
+            let is_eof = token == Token::Eof;
+            tokens.push((token, pos));
+            if is_eof {
+                return Ok(tokens);
+            }
+        }
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.pos = Position::new(self.pos.line + 1, 1);
+        } else {
+            self.pos.column += 1;
+        }
+
+        Some(c)
+    }
+
+    fn single(&mut self, token: Token) -> Token {
+        self.next_char();
+        token
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut s = String::new();
+        while let Some(c) = self.chars.peek().copied().filter(|c| predicate(*c)) {
+            s.push(c);
+            self.next_char();
+        }
+
+        s
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    fn color(&mut self) -> Result<Token, ParseError> {
+        let pos = self.pos;
+        self.next_char(); // '#'
+        let hex = self.take_while(|c| c.is_ascii_alphanumeric());
+
+        match (hex.len(), u32::from_str_radix(&hex, 16)) {
+            (6, Ok(rgb)) => Ok(Token::Color(Color::from_u32(rgb))),
+            _            => Err(ParseError::new(pos, format!("invalid color '#{hex}', expected #rrggbb"))),
+        }
+    }
+
+    fn number(&mut self) -> Result<Token, ParseError> {
+        let pos = self.pos;
+        let mut literal = String::new();
+        if self.chars.peek() == Some(&'-') {
+            literal.push('-');
+            self.next_char();
+        }
+        literal.push_str(&self.take_while(|c| c.is_ascii_digit() || c == '.'));
+
+        let value: f64 = literal.parse()
+            .map_err(|_| ParseError::new(pos, format!("invalid number '{literal}'")))?;
+
+        let unit = self.take_while(char::is_alphabetic);
+        match unit.as_str() {
+            ""                 => Ok(Token::Number(value)),
+            "ms"               => duration(value / 1000.0, pos).map(Token::Duration),
+            "s"                => duration(value, pos).map(Token::Duration),
+            _                  => Err(ParseError::new(pos, format!("unknown unit '{unit}', expected 'ms' or 's'"))),
+        }
+    }
+}
+
+/// Converts seconds into a [Duration], failing if the value is negative or does not fit.
+pub(crate) fn duration(secs: f64, pos: Position) -> Result<Duration, ParseError> {
+    if secs < 0.0 {
+        return Err(ParseError::new(pos, "durations cannot be negative"));
+    }
+
+    Duration::try_from_secs_f64(secs)
+        .map_err(|_| ParseError::new(pos, "duration is too large"))
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -0,0 +1,99 @@
+//! A small expression language for constructing effects from text.
+//!
+//! The language mirrors the constructors in [`fx`](crate::fx): effects are
+//! written as function calls, optionally followed by method calls such as
+//! `.with_filter(...)`, `.with_area(x, y, w, h)`, `.with_duration(d)`,
+//! `.with_time_scale(s)`, `.with_color_space(space)` and `.reversed()`.
+//!
+//! Arguments are one of:
+//! - **colors:** `#1d2021` or a named color such as `Red` or `LightBlue`
+//! - **durations:** `800ms`, `1.5s`, or a plain number of milliseconds
+//! - **timers:** a duration, optionally followed by an [`Interpolation`](crate::Interpolation),
+//!   e.g. `800ms QuadOut`, `500ms CubicBezier(0.4, 0, 0.2, 1)`, `1s Steps(4, JumpStart)`
+//!   or `600ms Spring(170, 12)`
+//! - **numbers** and **lists** of numbers, e.g. `[0, -100, 0]`
+//! - **directions:** `LeftToRight`, `RightToLeft`, `UpToDown`, `DownToUp`
+//! - **cell filters:** `All`, `Text`, `Empty`, `FgColor(c)`, `BgColor(c)`, `Inner(h, v)`,
+//!   `Outer(h, v)`, `AllOf(f, ...)`, `Not(f)`
+//! - **repeat modes:** `Forever`, `Times(n)`, `Duration(d)`
+//! - **color spaces:** `LinearRgb`, `Hsl`, `Oklab`, `Oklch`
+//! - `None`, for optional effects and hsl shifts
+//!
+//! Effects built from gradients, keyframes or particle emitters, and the transitions
+//! from a captured buffer, have no text form.
+//!
+//! # Example
+//! ```
+//! use tachyonfx::dsl;
+//!
+//! let effect = dsl::parse_effect(
+//!     "sequence(
+//!         fade_from(#1d2021, #1d2021, 800ms QuadOut),
+//!         sweep_in(LeftToRight, 30, #282828, 1s CircOut).with_filter(Text)
+//!     )"
+//! ).unwrap();
+//!
+//! let err = dsl::parse_effect("sequence(\n  fade_to(#1d2021, 800ms)\n)").err().unwrap();
+//! assert_eq!((err.line, err.column), (2, 3));
+//! assert_eq!(err.to_string(), "2:3: 'fade_to' expects 3 argument(s), found 2");
+//! ```
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::effect::Effect;
+
+mod eval;
+mod lexer;
+mod parser;
+
+/// Parses the input into an [`Effect`].
+///
+/// # Arguments
+/// * `input` - The effect expression to parse.
+///
+/// # Returns
+/// * The parsed effect, or a `ParseError` locating the first error in the input.
+pub fn parse_effect(input: &str) -> Result<Effect, ParseError> {
+    let tokens = lexer::tokenize(input)?;
+    let ast = parser::parse(tokens)?;
+    eval::effect(&ast)
+}
+
+/// An error encountered while parsing an effect expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// The column of the error, starting at 1.
+    pub column: usize,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(pos: Position, message: impl Into<String>) -> Self {
+        Self { line: pos.line, column: pos.column, message: message.into() }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line and column in the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
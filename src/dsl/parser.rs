@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use ratatui::style::Color;
+
+use crate::dsl::{ParseError, Position};
+use crate::dsl::lexer;
+use crate::dsl::lexer::Token;
+
+/// A node in the syntax tree, along with its position in the source.
+#[derive(Clone, Debug)]
+pub(crate) struct Node {
+    pub expr: Expr,
+    pub pos: Position,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum Expr {
+    /// `name(args...)`
+    Call { name: String, args: Vec<Node> },
+    /// `receiver.name(args...)`
+    Method { receiver: Box<Node>, name: String, args: Vec<Node> },
+    /// `[args...]`
+    List(Vec<Node>),
+    /// A bare identifier, e.g. `LeftToRight` or `Text`.
+    Ident(String),
+    Number(f64),
+    Duration(Duration),
+    /// A duration followed by an interpolation, e.g. `800ms QuadOut` or
+    /// `800ms CubicBezier(0.4, 0, 0.2, 1)`; the interpolation is an identifier or a call.
+    Timer(Duration, Box<Node>),
+    Color(Color),
+}
+
+/// Parses a single expression spanning all tokens.
+pub(crate) fn parse(tokens: Vec<(Token, Position)>) -> Result<Node, ParseError> {
+    let mut parser = Parser { tokens, current: 0 };
+    let node = parser.expr()?;
+    match parser.peek() {
+        Token::Eof => Ok(node),
+        token      => Err(parser.unexpected(&token.clone(), "end of input")),
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, Position)>,
+    current: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.current].0
+    }
+
+    fn pos(&self) -> Position {
+        self.tokens[self.current].1
+    }
+
+    fn advance(&mut self) -> (Token, Position) {
+        let token = self.tokens[self.current].clone();
+        if token.0 != Token::Eof {
+            self.current += 1;
+        }
+
+        token
+    }
+
+    fn expect(&mut self, expected: Token, description: &str) -> Result<(), ParseError> {
+        match self.advance() {
+            (token, _) if token == expected => Ok(()),
+            (token, pos) => Err(ParseError::new(pos, format!("expected {description}, found {}", describe(&token)))),
+        }
+    }
+
+    fn unexpected(&self, token: &Token, expected: &str) -> ParseError {
+        ParseError::new(self.pos(), format!("expected {expected}, found {}", describe(token)))
+    }
+
+    fn expr(&mut self) -> Result<Node, ParseError> {
+        let mut node = self.primary()?;
+        while *self.peek() == Token::Dot {
+            self.advance();
+            let pos = self.pos();
+            let name = match self.advance() {
+                (Token::Ident(name), _) => name,
+                (token, pos)            => return Err(ParseError::new(pos, format!("expected method name, found {}", describe(&token)))),
+            };
+            self.expect(Token::LParen, "'('")?;
+            let args = self.args(Token::RParen, "')'")?;
+            node = Node { expr: Expr::Method { receiver: Box::new(node), name, args }, pos };
+        }
+
+        Ok(node)
+    }
+
+    fn primary(&mut self) -> Result<Node, ParseError> {
+        let (token, pos) = self.advance();
+        let expr = match token {
+            Token::Ident(name) if *self.peek() == Token::LParen => {
+                self.advance();
+                Expr::Call { name, args: self.args(Token::RParen, "')'")? }
+            },
+            Token::Ident(name)     => Expr::Ident(name),
+            Token::Color(c)        => Expr::Color(c),
+            Token::LBracket        => Expr::List(self.args(Token::RBracket, "']'")?),
+            Token::Number(n)       => match self.interpolation()? {
+                Some(interpolation) => Expr::Timer(lexer::duration(n / 1000.0, pos)?, interpolation),
+                None                => Expr::Number(n),
+            },
+            Token::Duration(d)     => match self.interpolation()? {
+                Some(interpolation) => Expr::Timer(d, interpolation),
+                None                => Expr::Duration(d),
+            },
+            token => {
+                return Err(ParseError::new(pos, format!("expected expression, found {}", describe(&token))))
+            }
+        };
+
+        Ok(Node { expr, pos })
+    }
+
+    /// Consumes the interpolation trailing a duration, if any.
+    fn interpolation(&mut self) -> Result<Option<Box<Node>>, ParseError> {
+        match self.peek() {
+            Token::Ident(_) => self.primary().map(|node| Some(Box::new(node))),
+            _               => Ok(None),
+        }
+    }
+
+    /// Parses comma-separated expressions until the closing token.
+    fn args(&mut self, close: Token, description: &str) -> Result<Vec<Node>, ParseError> {
+        let mut args = Vec::new();
+        loop {
+            if *self.peek() == close {
+                self.advance();
+                return Ok(args);
+            }
+
+            args.push(self.expr()?);
+            match self.peek() {
+                Token::Comma            => { self.advance(); },
+                token if *token == close => {},
+                token                   => return Err(self.unexpected(&token.clone(), &format!("',' or {description}"))),
+            }
+        }
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Ident(name) => format!("'{name}'"),
+        Token::Number(n)   => format!("number {n}"),
+        Token::Duration(d) => format!("duration {d:?}"),
+        Token::Color(c)    => format!("color {c}"),
+        Token::LParen      => "'('".to_string(),
+        Token::RParen      => "')'".to_string(),
+        Token::LBracket    => "'['".to_string(),
+        Token::RBracket    => "']'".to_string(),
+        Token::Comma       => "','".to_string(),
+        Token::Dot         => "'.'".to_string(),
+        Token::Eof         => "end of input".to_string(),
+    }
+}
@@ -1,9 +1,11 @@
+use std::rc::Rc;
 use std::time::Duration;
 
 use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::{Margin, Position, Rect};
 use ratatui::style::Color;
-use crate::{CellIterator, EffectTimer};
+use crate::{CellIterator, ColorSpace, EffectTimer};
+use crate::effect_timer::{clamp_time_scale, scale_duration};
 use crate::shader::Shader;
 
 /// Represents an effect that can be applied to terminal cells.
@@ -11,6 +13,19 @@ use crate::shader::Shader;
 /// and applied to a specified area and cell selection.
 pub struct Effect {
     shader: Box<dyn Shader>,
+    paused: bool,
+    time_scale: f32,
+    hooks: EffectHooks,
+    started: bool,
+    completed: bool,
+}
+
+/// Callbacks invoked at the lifecycle boundaries of an [Effect].
+#[derive(Clone, Default)]
+pub(crate) struct EffectHooks {
+    on_start: Option<Rc<dyn Fn()>>,
+    on_loop: Option<Rc<dyn Fn(u32)>>,
+    on_complete: Option<Rc<dyn Fn()>>,
 }
 
 impl Effect {
@@ -24,7 +39,26 @@ impl Effect {
     pub fn new<S>(shader: S) -> Self
         where S: Shader + 'static
     {
-        Self { shader: Box::new(shader) }
+        Self {
+            shader: Box::new(shader),
+            paused: false,
+            time_scale: 1.0,
+            hooks: EffectHooks::default(),
+            started: false,
+            completed: false,
+        }
+    }
+
+    /// Removes the lifecycle hooks from the effect and returns them, e.g. when the
+    /// effect is wrapped by an effect which processes it out of order.
+    pub(crate) fn take_hooks(&mut self) -> EffectHooks {
+        std::mem::take(&mut self.hooks)
+    }
+
+    /// Returns the effect with its lifecycle hooks replaced by `hooks`.
+    pub(crate) fn with_hooks(mut self, hooks: EffectHooks) -> Self {
+        self.hooks = hooks;
+        self
     }
 
     /// Creates a new `Effect` with the specified area.
@@ -37,11 +71,10 @@ impl Effect {
     ///
     /// # Example
     /// ```
-    /// use tachyonfx::Effect;
+    /// use tachyonfx::fx;
     /// use ratatui::layout::Rect;
     ///
-    /// let shader = MyShader::new();
-    /// let effect = Effect::new(shader)
+    /// let effect = fx::dissolve(100, 500)
     ///     .with_area(Rect::new(0, 0, 10, 10));
     /// ```
     pub fn with_area(&self, area: Rect) -> Self {
@@ -60,10 +93,9 @@ impl Effect {
     ///
     /// # Example
     /// ```
-    /// use tachyonfx::{Effect, CellFilter};
+    /// use tachyonfx::{fx, CellFilter};
     ///
-    /// let shader = MyShader::new();
-    /// let effect = Effect::new(shader)
+    /// let effect = fx::dissolve(100, 500)
     ///     .with_cell_selection(CellFilter::Text);
     /// ```
     pub fn with_cell_selection(&self, mode: CellFilter) -> Self {
@@ -72,6 +104,198 @@ impl Effect {
         cloned
     }
 
+    /// Creates a new `Effect` interpolating colors in the specified color space, instead
+    /// of the [global color space](crate::global_color_space).
+    ///
+    /// # Arguments
+    /// * `color_space` - The color space to interpolate colors in.
+    ///
+    /// # Returns
+    /// * A new `Effect` instance with the specified color space.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, ColorSpace};
+    ///
+    /// let effect = fx::fade_to_fg(Color::Rgb(0x1d, 0x20, 0x21), 500)
+    ///     .with_color_space(ColorSpace::Oklab);
+    /// ```
+    pub fn with_color_space(&self, color_space: ColorSpace) -> Self {
+        let mut cloned = self.clone();
+        cloned.set_color_space(color_space);
+        cloned
+    }
+
+    /// Creates a new `Effect` with the specified time scale. See [Effect::set_time_scale].
+    ///
+    /// # Arguments
+    /// * `time_scale` - The time scale multiplier.
+    ///
+    /// # Returns
+    /// * A new `Effect` instance with the specified time scale.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let effect = fx::dissolve(100, 500)
+    ///     .with_time_scale(0.5); // half speed
+    /// assert_eq!(effect.duration(), Some(Duration::from_millis(1000)));
+    /// ```
+    pub fn with_time_scale(&self, time_scale: f32) -> Self {
+        let mut cloned = self.clone();
+        cloned.set_time_scale(time_scale);
+        cloned
+    }
+
+    /// Sets the time scale multiplier of the effect. Durations passed to `process`
+    /// are multiplied by the time scale; e.g. `0.5` plays the effect at half speed and
+    /// `2.0` at double speed. Time scales of nested effects compound.
+    ///
+    /// # Arguments
+    /// * `time_scale` - The time scale multiplier, in the range `0.0..=1000.0`; values
+    ///   outside the range are clamped to it, and NaN leaves the time scale unchanged.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = clamp_time_scale(time_scale, self.time_scale);
+    }
+
+    /// Returns the time scale multiplier of the effect.
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Pauses the effect. A paused effect keeps being applied to the buffer,
+    /// but it does not advance in time and never reports any overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut buf = Buffer::empty(area);
+    ///
+    /// let mut effect = fx::dissolve(10, 100);
+    /// effect.pause();
+    /// effect.process(Duration::from_millis(500), &mut buf, area);
+    /// assert!(effect.running());
+    ///
+    /// effect.resume();
+    /// effect.process(Duration::from_millis(500), &mut buf, area);
+    /// assert!(effect.done());
+    /// ```
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes a paused effect.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns true if the effect is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Creates a new `Effect` which calls `f` the first time it is processed.
+    ///
+    /// Hooks are shared between clones of the effect, but each clone tracks its
+    /// own progress; e.g. the child of a [`repeat`](crate::fx::repeat) starts
+    /// anew on every iteration.
+    ///
+    /// # Arguments
+    /// * `f` - The callback to invoke when the effect starts.
+    ///
+    /// # Returns
+    /// * A new `Effect` instance with the start hook.
+    pub fn on_start(&self, f: impl Fn() + 'static) -> Self {
+        let mut cloned = self.clone();
+        cloned.hooks.on_start = Some(Rc::new(f));
+        cloned
+    }
+
+    /// Creates a new `Effect` which calls `f` whenever a looping effect, such as
+    /// [`repeat`](crate::fx::repeat) or [`ping_pong`](crate::fx::ping_pong),
+    /// completes an iteration. The callback receives the number of completed
+    /// iterations. See [Shader::loop_count].
+    ///
+    /// # Arguments
+    /// * `f` - The callback to invoke at each loop boundary.
+    ///
+    /// # Returns
+    /// * A new `Effect` instance with the loop hook.
+    ///
+    /// # Example
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut buf = Buffer::empty(area);
+    ///
+    /// let loops = Rc::new(Cell::new(0));
+    /// let counter = loops.clone();
+    /// let mut effect = fx::repeating(fx::dissolve(10, 100))
+    ///     .on_loop(move |n| counter.set(n));
+    ///
+    /// for _ in 0..5 {
+    ///     effect.process(Duration::from_millis(60), &mut buf, area);
+    /// }
+    /// assert_eq!(loops.get(), 2);
+    /// ```
+    pub fn on_loop(&self, f: impl Fn(u32) + 'static) -> Self {
+        let mut cloned = self.clone();
+        cloned.hooks.on_loop = Some(Rc::new(f));
+        cloned
+    }
+
+    /// Creates a new `Effect` which calls `f` once, when the effect is done.
+    ///
+    /// # Arguments
+    /// * `f` - The callback to invoke when the effect completes.
+    ///
+    /// # Returns
+    /// * A new `Effect` instance with the completion hook.
+    ///
+    /// # Example
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use std::time::Duration;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let mut buf = Buffer::empty(area);
+    ///
+    /// let popup_open = Rc::new(Cell::new(true));
+    /// let state = popup_open.clone();
+    /// let mut effect = fx::coalesce(10, 300)
+    ///     .reversed()
+    ///     .on_complete(move || state.set(false));
+    ///
+    /// effect.process(Duration::from_millis(200), &mut buf, area);
+    /// assert!(popup_open.get());
+    ///
+    /// effect.process(Duration::from_millis(200), &mut buf, area);
+    /// assert!(!popup_open.get());
+    /// ```
+    pub fn on_complete(&self, f: impl Fn() + 'static) -> Self {
+        let mut cloned = self.clone();
+        cloned.hooks.on_complete = Some(Rc::new(f));
+        cloned
+    }
+
     /// Creates a new `Effect` with the shader's reverse flag toggled.
     ///
     /// # Returns
@@ -99,6 +323,8 @@ pub enum CellFilter {
     Outer(Margin),
     /// Selects cells with text
     Text,
+    /// Selects empty cells, with a blank symbol
+    Empty,
     /// Selects cells that match all the given filters
     AllOf(Vec<CellFilter>),
     /// Negates the given filter
@@ -123,6 +349,7 @@ impl CellSelector {
             CellFilter::Inner(margin) => area.inner(margin),
             CellFilter::Outer(margin) => area.inner(margin),
             CellFilter::Text          => area,
+            CellFilter::Empty         => area,
             CellFilter::AllOf(_)      => area,
             CellFilter::Not(m)        => Self::resolve_area(area, m.as_ref()),
             CellFilter::FgColor(_)    => area,
@@ -143,6 +370,7 @@ impl CellSelector {
             CellFilter::Inner(_)   => self.inner_area.contains(pos),
             CellFilter::Outer(_)   => !self.inner_area.contains(pos),
             CellFilter::Text       => self.inner_area.contains(pos),
+            CellFilter::Empty      => self.inner_area.contains(pos),
             CellFilter::AllOf(s)   => s.iter()
                 .all(|mode| mode.selector(self.inner_area).valid_position(pos, mode)),
             CellFilter::Not(m)  => self.valid_position(pos, m.as_ref()),
@@ -162,6 +390,8 @@ impl CellSelector {
                 }
             },
 
+            CellFilter::Empty => cell.symbol().trim().is_empty(),
+
             CellFilter::AllOf(s) => {
                 s.iter()
                     .all(|s| s.selector(self.inner_area).is_valid_cell(cell, s))
@@ -185,14 +415,46 @@ impl CellFilter {
 
 impl Clone for Effect {
     fn clone(&self) -> Self {
-        Self { shader: self.shader.clone_box() }
+        Self {
+            shader: self.shader.clone_box(),
+            paused: self.paused,
+            time_scale: self.time_scale,
+            hooks: self.hooks.clone(),
+            started: self.started,
+            completed: self.completed,
+        }
     }
 }
 
 impl Shader for Effect {
     fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
         let area = self.shader.area().unwrap_or(area);
-        self.shader.process(duration, buf, area)
+        if self.paused {
+            self.shader.process(Duration::ZERO, buf, area);
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+            if let Some(f) = &self.hooks.on_start { f() }
+        }
+
+        let loops = self.shader.loop_count();
+        let overflow = self.shader.process(scale_duration(duration, self.time_scale), buf, area);
+
+        if let Some(f) = &self.hooks.on_loop {
+            (loops..self.shader.loop_count()).for_each(|n| f(n + 1));
+        }
+
+        if !self.completed && self.shader.done() {
+            self.completed = true;
+            if let Some(f) = &self.hooks.on_complete { f() }
+        }
+
+        match self.time_scale {
+            s if s > 0.0 => overflow.map(|d| scale_duration(d, 1.0 / s)),
+            _            => overflow,
+        }
     }
 
     fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator){
@@ -204,7 +466,7 @@ impl Shader for Effect {
     }
 
     fn clone_box(&self) -> Box<dyn Shader> {
-        self.shader.clone_box()
+        Box::new(self.clone())
     }
 
     fn area(&self) -> Option<Rect> {
@@ -219,6 +481,10 @@ impl Shader for Effect {
         self.shader.set_cell_selection(strategy)
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.shader.set_color_space(color_space)
+    }
+
     fn reverse(&mut self) {
         self.shader.reverse()
     }
@@ -227,6 +493,28 @@ impl Shader for Effect {
         self.shader.timer_mut()
     }
 
+    fn timer(&self) -> Option<&EffectTimer> {
+        self.shader.timer()
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        match self.time_scale {
+            s if s > 0.0 => self.shader.duration().map(|d| scale_duration(d, 1.0 / s)),
+            _            => None,
+        }
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.shader.seek(scale_duration(elapsed, self.time_scale));
+        if self.shader.running() {
+            self.completed = false;
+        }
+    }
+
+    fn loop_count(&self) -> u32 {
+        self.shader.loop_count()
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         self.shader.cell_selection()
     }
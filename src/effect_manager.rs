@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::effect::Effect;
+use crate::color_depth::quantize_to_global_depth;
+use crate::effect_timer::{global_time_scale, scale_duration};
+use crate::shader::Shader;
+
+/// Owns and drives a collection of effects. The `EffectManager` processes all
+/// of its effects in a single call and removes them once they report `done()`.
+///
+/// Effects can optionally be registered under a unique key; adding a new effect
+/// with the same key replaces (cancels) the previously registered one.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{fx, EffectManager};
+///
+/// let area = Rect::new(0, 0, 10, 10);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut effects: EffectManager<&str> = EffectManager::default();
+/// effects.add_effect(fx::dissolve(10, 100));
+/// effects.add_unique_effect("popup", fx::coalesce(10, 500));
+/// effects.add_unique_effect("popup", fx::coalesce(10, 200)); // replaces the first
+/// assert_eq!(effects.len(), 2);
+///
+/// effects.process_effects(Duration::from_millis(150), &mut buf, area);
+/// assert_eq!(effects.len(), 1);
+/// ```
+pub struct EffectManager<K = ()> {
+    effects: Vec<(Option<K>, Effect)>,
+}
+
+impl<K: PartialEq> EffectManager<K> {
+
+    /// Adds an effect to the manager. The effect is processed until it is done.
+    ///
+    /// # Arguments
+    /// * `effect` - The effect to add.
+    pub fn add_effect(&mut self, effect: Effect) {
+        self.effects.push((None, effect));
+    }
+
+    /// Adds an effect identified by `key`. Any running effect registered under
+    /// the same key is cancelled and replaced by the new effect.
+    ///
+    /// # Arguments
+    /// * `key` - The unique key identifying the effect.
+    /// * `effect` - The effect to add.
+    pub fn add_unique_effect(&mut self, key: K, effect: Effect) {
+        self.cancel_unique_effect(&key);
+        self.effects.push((Some(key), effect));
+    }
+
+    /// Cancels the effect registered under `key`, if any.
+    ///
+    /// # Arguments
+    /// * `key` - The unique key identifying the effect.
+    ///
+    /// # Returns
+    /// * `true` if an effect was cancelled, `false` otherwise.
+    pub fn cancel_unique_effect(&mut self, key: &K) -> bool {
+        let len = self.effects.len();
+        self.effects.retain(|(k, _)| k.as_ref() != Some(key));
+        len != self.effects.len()
+    }
+
+    /// Returns true if an effect is registered under `key`.
+    pub fn is_running_unique_effect(&self, key: &K) -> bool {
+        self.effects.iter().any(|(k, _)| k.as_ref() == Some(key))
+    }
+
+    /// Processes all effects for the given duration and removes the ones
+    /// that have completed. Effects without an explicit area are applied
+    /// to `area`. The duration is scaled by the [global time scale](crate::global_time_scale).
+    /// Finally, the areas of the processed effects are quantized to the
+    /// [global color depth](crate::global_color_depth).
+    ///
+    /// # Arguments
+    /// * `duration` - The duration to process the effects for.
+    /// * `buf` - A mutable reference to the `Buffer` where the effects will be applied.
+    /// * `area` - The default area for effects without an area of their own.
+    pub fn process_effects(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) {
+        let duration = scale_duration(duration, global_time_scale());
+        let rendered = self.effects.iter_mut()
+            .filter(|(_, effect)| effect.running())
+            .map(|(_, effect)| {
+                effect.process(duration, buf, area);
+                effect.area().unwrap_or(area)
+            })
+            .reduce(|a, b| a.union(b));
+
+        self.effects.retain(|(_, effect)| effect.running());
+        if let Some(rendered) = rendered {
+            quantize_to_global_depth(buf, rendered);
+        }
+    }
+
+    /// Returns true if any effect is still running.
+    pub fn is_running(&self) -> bool {
+        !self.effects.is_empty()
+    }
+
+    /// Returns the number of effects currently managed.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns true if there are no effects being managed.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Removes all effects.
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+}
+
+impl<K> Default for EffectManager<K> {
+    fn default() -> Self {
+        Self { effects: Vec::new() }
+    }
+}
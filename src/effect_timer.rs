@@ -1,6 +1,60 @@
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::Duration;
 use crate::interpolation::Interpolation;
 
+/// The global time scale, stored as the bits of an `f32`. Defaults to `1.0`.
+static GLOBAL_TIME_SCALE: AtomicU32 = AtomicU32::new(0x3f80_0000);
+
+/// Sets the global time scale multiplier, applied to the durations passed to
+/// [EffectRenderer::render_effect](crate::EffectRenderer::render_effect) and
+/// [EffectManager::process_effects](crate::EffectManager::process_effects).
+/// Useful for e.g. slow-motion debugging of effects.
+///
+/// # Arguments
+/// * `time_scale` - The time scale multiplier, in the range `0.0..=1000.0`; values outside
+///   the range are clamped to it, and NaN leaves the time scale unchanged.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{global_time_scale, set_global_time_scale};
+///
+/// set_global_time_scale(0.25); // quarter speed
+/// assert_eq!(global_time_scale(), 0.25);
+/// set_global_time_scale(f32::INFINITY);
+/// assert_eq!(global_time_scale(), 1000.0);
+/// set_global_time_scale(1.0);
+/// ```
+pub fn set_global_time_scale(time_scale: f32) {
+    let time_scale = clamp_time_scale(time_scale, global_time_scale());
+    GLOBAL_TIME_SCALE.store(time_scale.to_bits(), Ordering::Relaxed);
+}
+
+/// Returns the global time scale multiplier.
+pub fn global_time_scale() -> f32 {
+    f32::from_bits(GLOBAL_TIME_SCALE.load(Ordering::Relaxed))
+}
+
+/// The largest supported time scale multiplier.
+const MAX_TIME_SCALE: f32 = 1000.0;
+
+/// Clamps the time scale to `0.0..=MAX_TIME_SCALE`, falling back to `current` for NaN.
+pub(crate) fn clamp_time_scale(time_scale: f32, current: f32) -> f32 {
+    match time_scale.is_nan() {
+        true  => current,
+        false => time_scale.clamp(0.0, MAX_TIME_SCALE),
+    }
+}
+
+/// Multiplies the duration by the time scale, saturating at [Duration::MAX].
+pub(crate) fn scale_duration(duration: Duration, time_scale: f32) -> Duration {
+    if time_scale == 1.0 || duration.is_zero() {
+        duration
+    } else {
+        Duration::try_from_secs_f32(duration.as_secs_f32() * time_scale)
+            .unwrap_or(Duration::MAX)
+    }
+}
+
 /// A struct for managing the timing and interpolation of effects.
 /// The `EffectTimer` controls the duration and progress of an effect, allowing it to be reversed,
 /// reset, and processed over time.
@@ -109,6 +163,52 @@ impl EffectTimer {
         self.remaining = self.total;
     }
 
+    /// Returns the total duration of the timer.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation};
+    /// let timer = EffectTimer::from_ms(1000, Interpolation::Linear);
+    /// assert_eq!(timer.duration(), Duration::from_secs(1));
+    /// ```
+    pub fn duration(&self) -> Duration {
+        self.total
+    }
+
+    /// Returns the time elapsed since the timer started.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation};
+    /// let mut timer = EffectTimer::from_ms(1000, Interpolation::Linear);
+    /// timer.process(Duration::from_millis(300));
+    /// assert_eq!(timer.elapsed(), Duration::from_millis(300));
+    /// ```
+    pub fn elapsed(&self) -> Duration {
+        self.total - self.remaining
+    }
+
+    /// Sets the elapsed time of the timer, clamped to its total duration.
+    ///
+    /// # Arguments
+    /// * `elapsed` - The elapsed time to jump to.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{EffectTimer, Interpolation};
+    /// let mut timer = EffectTimer::from_ms(1000, Interpolation::Linear);
+    /// timer.set_elapsed(Duration::from_millis(250));
+    /// assert_eq!(timer.alpha(), 0.25);
+    /// timer.set_elapsed(Duration::from_secs(5));
+    /// assert!(timer.done());
+    /// ```
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.remaining = self.total.saturating_sub(elapsed);
+    }
+
     /// Computes the current alpha value based on the elapsed time and interpolation method.
     ///
     /// # Returns
@@ -133,6 +233,17 @@ impl EffectTimer {
         self.interpolation.alpha(a)
     }
 
+    /// Returns the interpolation of the timer.
+    pub(crate) fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the point in time corresponding to the current [alpha](EffectTimer::alpha),
+    /// i.e. the elapsed time after interpolation and reversal have been applied.
+    pub(crate) fn alpha_elapsed(&self) -> Duration {
+        self.total.mul_f32(self.alpha().clamp(0.0, 1.0))
+    }
+
     /// Processes the timer by reducing the remaining duration by the specified amount.
     ///
     /// # Arguments
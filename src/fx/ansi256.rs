@@ -3,18 +3,18 @@ use std::time::Duration;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 
-use crate::CellIterator;
 use crate::color_ext::AsIndexedColor;
 use crate::color_mapper::ColorMapper;
-use crate::effect::CellFilter;
-use crate::shader::Shader;
+use crate::Shader;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Shader)]
+#[shader(process, done)]
 pub struct Ansi256 {
+    #[shader(area)]
     area: Option<Rect>,
 }
 
-impl Shader for Ansi256 {
+impl Ansi256 {
     fn process(
         &mut self,
         _duration: Duration,
@@ -38,23 +38,5 @@ impl Shader for Ansi256 {
         None
     }
 
-    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
-        // handled by process
-    }
-
     fn done(&self) -> bool { false }
-
-    fn clone_box(&self) -> Box<dyn Shader> {
-        Box::new(self.clone())
-    }
-
-    fn area(&self) -> Option<Rect> {
-        self.area
-    }
-
-    fn set_area(&mut self, area: Rect) {
-        self.area = Some(area);
-    }
-
-    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
 }
\ No newline at end of file
@@ -35,4 +35,12 @@ impl Shader for ConsumeTick {
     fn area(&self) -> Option<Rect> { None }
     fn set_area(&mut self, _area: Rect) {}
     fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn duration(&self) -> Option<Duration> {
+        Some(Duration::ZERO)
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.has_consumed_tick = !elapsed.is_zero();
+    }
 }
@@ -1,7 +1,7 @@
 use std::time::Duration;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Rect};
-use crate::{CellIterator, EffectTimer};
+use crate::{CellIterator, ColorSpace, EffectTimer};
 use crate::effect::{Effect, CellFilter};
 use crate::shader::Shader;
 
@@ -68,6 +68,10 @@ impl Shader for ParallelEffect {
         self.effects.iter_mut().for_each(|e| e.set_cell_selection(strategy.clone()));
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.effects.iter_mut().for_each(|e| e.set_color_space(color_space));
+    }
+
     fn reverse(&mut self) {
         self.effects.iter_mut().for_each(Effect::reverse)
     }
@@ -79,6 +83,16 @@ impl Shader for ParallelEffect {
     fn cell_selection(&self) -> Option<CellFilter> {
         None
     }
+
+    fn duration(&self) -> Option<Duration> {
+        self.effects.iter()
+            .map(Effect::duration)
+            .try_fold(Duration::ZERO, |acc, d| d.map(|d| acc.max(d)))
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.effects.iter_mut().for_each(|e| e.seek(elapsed));
+    }
 }
 
 impl Shader for SequentialEffect {
@@ -125,6 +139,10 @@ impl Shader for SequentialEffect {
         self.effects.iter_mut().for_each(|e| e.set_cell_selection(strategy.clone()));
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.effects.iter_mut().for_each(|e| e.set_color_space(color_space));
+    }
+
     fn reverse(&mut self) {
         self.effects.iter_mut().for_each(Effect::reverse)
     }
@@ -132,4 +150,32 @@ impl Shader for SequentialEffect {
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
 
     fn cell_selection(&self) -> Option<CellFilter> { None }
+
+    fn duration(&self) -> Option<Duration> {
+        self.effects.iter()
+            .map(Effect::duration)
+            .try_fold(Duration::ZERO, |acc, d| d.map(|d| acc + d))
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        // effects before the one containing `elapsed` are completed, effects
+        // after it are rewound to their start.
+        let mut remaining = Some(elapsed);
+        self.current = self.effects.len();
+
+        for (idx, effect) in self.effects.iter_mut().enumerate() {
+            match (remaining, effect.duration()) {
+                (Some(r), Some(d)) if r > d || (r == d && !d.is_zero()) => {
+                    effect.seek(d);
+                    remaining = Some(r - d);
+                },
+                (Some(r), _) => {
+                    effect.seek(r);
+                    self.current = idx;
+                    remaining = None;
+                },
+                (None, _) => effect.seek(Duration::ZERO),
+            }
+        }
+    }
 }
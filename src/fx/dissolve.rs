@@ -1,27 +1,48 @@
-use rand::{Rng, thread_rng};
+use rand::Rng;
 use rand::prelude::{SeedableRng, SmallRng};
 use ratatui::layout::Rect;
 
 use crate::CellIterator;
 use crate::effect::CellFilter;
 use crate::effect_timer::EffectTimer;
-use crate::shader::Shader;
+use crate::rng::default_rng;
+use crate::Shader;
 
-#[derive(Clone)]
+#[derive(Clone, Shader)]
 pub struct Dissolve {
+    #[shader(timer)]
     lifetime: EffectTimer,
     cyclic_cell_activation: Vec<f32>,
+    #[shader(area)]
     area: Option<Rect>,
+    #[shader(filter)]
     cell_filter: CellFilter,
 }
 
 impl Dissolve {
+    /// Creates a new `Dissolve` using the default random number generator;
+    /// see [`rng`](crate::rng).
     pub fn new(
         lifetime: EffectTimer,
         cell_cycle: usize,
     ) -> Self {
-        let mut rng = SmallRng::from_rng(thread_rng()).unwrap();
+        Self::with_rng(lifetime, cell_cycle, default_rng())
+    }
 
+    /// Creates a new `Dissolve` with a deterministic cell activation order.
+    pub fn with_seed(
+        lifetime: EffectTimer,
+        cell_cycle: usize,
+        seed: u64,
+    ) -> Self {
+        Self::with_rng(lifetime, cell_cycle, SmallRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(
+        lifetime: EffectTimer,
+        cell_cycle: usize,
+        mut rng: SmallRng,
+    ) -> Self {
         Self {
             lifetime,
             cyclic_cell_activation: (0..cell_cycle).map(|_| rng.gen_range(0.0..1.0)).collect(),
@@ -33,45 +54,10 @@ impl Dissolve {
     fn is_cell_idx_active(&self, idx: usize, a: f32) -> bool {
         a > self.cyclic_cell_activation[idx % self.cyclic_cell_activation.len()]
     }
-}
-
-impl Shader for Dissolve {
 
     fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
         cell_iter.enumerate()
             .filter(|(idx, _)| self.is_cell_idx_active(*idx, alpha))
             .for_each(|(_, (_, c))| { c.set_char(' '); });
     }
-
-    fn done(&self) -> bool {
-          self.lifetime.done()
-     }
-
-     fn clone_box(&self) -> Box<dyn Shader> {
-          Box::new(self.clone())
-     }
-
-    fn area(&self) -> Option<Rect> {
-        self.area
-    }
-
-    fn set_area(&mut self, area: Rect) {
-        self.area = Some(area)
-    }
-
-    fn set_cell_selection(&mut self, strategy: CellFilter) {
-        self.cell_filter = strategy
-    }
-
-    fn reverse(&mut self) {
-        self.lifetime = self.lifetime.reversed();
-    }
-
-    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
-        Some(&mut self.lifetime)
-    }
-
-    fn cell_selection(&self) -> Option<CellFilter> {
-        Some(self.cell_filter.clone())
-    }
 }
\ No newline at end of file
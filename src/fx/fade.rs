@@ -2,21 +2,41 @@ use derive_builder::Builder;
 use ratatui::layout::Rect;
 use ratatui::prelude::Color;
 
-use crate::{CellIterator, Interpolatable};
+use crate::{CellIterator, ColorSpace, Gradient};
 use crate::color_mapper::ColorMapper;
+use crate::color_space::global_color_space;
 use crate::effect::{CellFilter, Effect, IntoEffect};
 use crate::effect_timer::EffectTimer;
-use crate::shader::Shader;
+use crate::keyframes::Keyframes;
+use crate::Shader;
 
-#[derive(Builder, Clone)]
+#[derive(Builder, Clone, Shader)]
 #[builder(pattern = "owned")]
 pub struct FadeColors {
     fg: Option<Color>,
     bg: Option<Color>,
+    /// Takes precedence over `fg`; the first segment leads from the cell's color.
+    #[builder(default)]
+    fg_keyframes: Option<Keyframes<Color>>,
+    /// Takes precedence over `bg`; the first segment leads from the cell's color.
+    #[builder(default)]
+    bg_keyframes: Option<Keyframes<Color>>,
+    /// Takes precedence over `fg`; each cell fades towards its color in the gradient.
+    #[builder(default)]
+    fg_gradient: Option<Gradient>,
+    /// Takes precedence over `bg`; each cell fades towards its color in the gradient.
+    #[builder(default)]
+    bg_gradient: Option<Gradient>,
+    #[shader(timer)]
     lifetime: EffectTimer,
     #[builder(default)]
+    #[shader(color_space)]
+    color_space: Option<ColorSpace>,
+    #[builder(default)]
+    #[shader(area)]
     area: Option<Rect>,
     #[builder(default)]
+    #[shader(filter)]
     cell_filter: CellFilter,
 }
 
@@ -32,53 +52,37 @@ impl From<FadeColorsBuilder> for Effect {
     }
 }
 
-impl Shader for FadeColors {
-    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+impl FadeColors {
+    fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator) {
         let mut fg_mapper = ColorMapper::default();
         let mut bg_mapper = ColorMapper::default();
+        let elapsed = self.lifetime.alpha_elapsed();
+        let space = self.color_space.unwrap_or_else(global_color_space);
+        let lerp_fg = |from: &Color, to: &Color, a: f32| space.lerp(from, to, a);
+        let lerp_bg = |from: &Color, to: &Color, a: f32| space.lerp_bg(from, to, a);
 
-        cell_iter.for_each(|(_, cell)| {
-            if let Some(fg) = self.fg.as_ref() {
-                let color = fg_mapper.map(cell.fg, alpha, |c| c.lerp(fg, alpha));
+        cell_iter.for_each(|(pos, cell)| {
+            if let Some(keyframes) = self.fg_keyframes.as_ref() {
+                let color = fg_mapper.map(cell.fg, alpha, |c| keyframes.value_from_with(&c, elapsed, lerp_fg));
+                cell.set_fg(color);
+            } else if let Some(gradient) = self.fg_gradient.as_ref() {
+                let fg = gradient.color_at_with(gradient.position(area, pos), lerp_fg);
+                cell.set_fg(space.lerp(&cell.fg, &fg, alpha));
+            } else if let Some(fg) = self.fg.as_ref() {
+                let color = fg_mapper.map(cell.fg, alpha, |c| space.lerp(&c, fg, alpha));
                 cell.set_fg(color);
             }
 
-            if let Some(bg) = self.bg.as_ref() {
-                let color = bg_mapper.map(cell.bg, alpha, |c| c.lerp(bg, alpha));
+            if let Some(keyframes) = self.bg_keyframes.as_ref() {
+                let color = bg_mapper.map(cell.bg, alpha, |c| keyframes.value_from_with(&c, elapsed, lerp_bg));
+                cell.set_bg(color);
+            } else if let Some(gradient) = self.bg_gradient.as_ref() {
+                let bg = gradient.color_at_with(gradient.position(area, pos), lerp_bg);
+                cell.set_bg(space.lerp_bg(&cell.bg, &bg, alpha));
+            } else if let Some(bg) = self.bg.as_ref() {
+                let color = bg_mapper.map(cell.bg, alpha, |c| space.lerp_bg(&c, bg, alpha));
                 cell.set_bg(color);
             }
         });
     }
-
-    fn done(&self) -> bool {
-        self.lifetime.done()
-    }
-
-    fn clone_box(&self) -> Box<dyn Shader> {
-        Box::new(self.clone())
-    }
-
-    fn area(&self) -> Option<Rect> {
-        self.area
-    }
-
-    fn set_area(&mut self, area: Rect) {
-        self.area = Some(area);
-    }
-
-    fn set_cell_selection(&mut self, strategy: CellFilter) {
-        self.cell_filter = strategy;
-    }
-
-    fn reverse(&mut self) {
-        self.lifetime = self.lifetime.reversed();
-    }
-
-    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
-        Some(&mut self.lifetime)
-    }
-
-    fn cell_selection(&self) -> Option<CellFilter> {
-        Some(self.cell_filter.clone())
-    }
 }
@@ -3,13 +3,13 @@ use std::ops::Range;
 use std::time::Duration;
 
 use derive_builder::Builder;
-use rand::prelude::SmallRng;
+use rand::prelude::{SeedableRng, SmallRng};
 use rand::Rng;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Position, Rect};
-use crate::{CellIterator, EffectTimer};
 use crate::effect::{Effect, CellFilter, IntoEffect};
-use crate::shader::Shader;
+use crate::rng::default_rng;
+use crate::Shader;
 
 
 /// Type of glitch transformation to apply to a cell.
@@ -35,22 +35,35 @@ impl GlitchCell {
 }
 
 /// applies a glitch effect to random parts of the screen.
-#[derive(Builder, Clone, Debug)]
+#[derive(Builder, Clone, Debug, Shader)]
 #[builder(pattern = "owned")]
+#[shader(process, done)]
 pub struct Glitch {
     cell_glitch_ratio: f32,
     action_start_delay_ms: Range<u32>,
     action_ms: Range<u32>,
+    #[builder(default = "default_rng()")]
     rng: SmallRng,
     #[builder(default)]
+    #[shader(filter)]
     selection: CellFilter,
 
     #[builder(setter(skip))]
     glitch_cells: Vec<GlitchCell>,
     #[builder(default)]
+    #[shader(area)]
     area: Option<Rect>,
 }
 
+impl GlitchBuilder {
+    /// Seeds the random number generator, making the glitches deterministic.
+    /// Without a seed or explicit `rng`, the default random number generator
+    /// is used; see [`rng`](crate::rng).
+    pub fn seed(self, seed: u64) -> Self {
+        self.rng(SmallRng::seed_from_u64(seed))
+    }
+}
+
 impl From<GlitchBuilder> for Effect {
     fn from(value: GlitchBuilder) -> Self {
         value.build().unwrap().into_effect()
@@ -102,9 +115,7 @@ impl Glitch {
             _ => unreachable!(),
         }
     }
-}
 
-impl Shader for Glitch {
     fn process(
         &mut self,
         duration: Duration,
@@ -168,31 +179,7 @@ impl Shader for Glitch {
         None
     }
 
-    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
-
     fn done(&self) -> bool {
         false
     }
-
-    fn clone_box(&self) -> Box<dyn Shader> {
-        Box::new(self.clone())
-    }
-
-    fn area(&self) -> Option<Rect> {
-        self.area
-    }
-
-    fn set_area(&mut self, area: Rect) {
-        self.area = Some(area)
-    }
-
-    fn set_cell_selection(&mut self, strategy: CellFilter) {
-        self.selection = strategy;
-    }
-
-    fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
-
-    fn cell_selection(&self) -> Option<CellFilter> {
-        Some(self.selection.clone())
-    }
 }
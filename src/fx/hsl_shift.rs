@@ -2,22 +2,39 @@ use derive_builder::Builder;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
 
-use crate::{CellIterator, ColorMapper, Effect, HslConvertable, Interpolatable, IntoEffect};
+use crate::{CellIterator, ColorMapper, ColorSpace, Effect, HslConvertable, Interpolatable, IntoEffect};
+use crate::color_space::global_color_space;
+use crate::palette::resolve;
 use crate::effect::CellFilter;
 use crate::effect_timer::EffectTimer;
-use crate::shader::Shader;
+use crate::keyframes::Keyframes;
+use crate::Shader;
 
-#[derive(Clone, Default, Builder)]
+#[derive(Clone, Default, Builder, Shader)]
 #[builder(pattern = "owned")]
 pub struct HslShift {
+    #[shader(timer)]
     lifetime: EffectTimer,
     #[builder(default)]
     hsl_mod_fg: Option<[f32; 3]>,
     #[builder(default)]
     hsl_mod_bg: Option<[f32; 3]>,
+    /// Takes precedence over `hsl_mod_fg`; the shift is zero at the start, and is
+    /// applied as is, regardless of the color space.
     #[builder(default)]
+    hsl_keyframes_fg: Option<Keyframes<[f32; 3]>>,
+    /// Takes precedence over `hsl_mod_bg`; the shift is zero at the start, and is
+    /// applied as is, regardless of the color space.
+    #[builder(default)]
+    hsl_keyframes_bg: Option<Keyframes<[f32; 3]>>,
+    #[builder(default)]
+    #[shader(color_space)]
+    color_space: Option<ColorSpace>,
+    #[builder(default)]
+    #[shader(area)]
     area: Option<Rect>,
     #[builder(default)]
+    #[shader(filter)]
     cell_filter: CellFilter,
 }
 
@@ -27,64 +44,57 @@ impl HslShift {
     }
 }
 
-impl Shader for HslShift {
+impl HslShift {
     fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
         let mut fg_mapper = ColorMapper::default();
         let mut bg_mapper = ColorMapper::default();
 
-        let hsl_lerp = |c: Color, hsl: [f32; 3]| -> Color {
+        // in HSL, the shift itself is interpolated; in any other color space, the
+        // fully shifted color is blended with the original color
+        let space = self.color_space.unwrap_or_else(global_color_space);
+        let (shift_alpha, blend_alpha) = match space {
+            ColorSpace::Hsl => (alpha, 1.0),
+            _               => (1.0, alpha),
+        };
+
+        let elapsed = self.lifetime.alpha_elapsed();
+        let shift_at = |hsl_mod: Option<[f32; 3]>, keyframes: Option<&Keyframes<[f32; 3]>>| {
+            match keyframes {
+                Some(keyframes) => Some((keyframes.value_from(&[0.0; 3], elapsed), 1.0)),
+                None            => hsl_mod.map(|hsl| ([0.0; 3].lerp(&hsl, shift_alpha), blend_alpha)),
+            }
+        };
+        let shift_fg = shift_at(self.hsl_mod_fg, self.hsl_keyframes_fg.as_ref());
+        let shift_bg = shift_at(self.hsl_mod_bg, self.hsl_keyframes_bg.as_ref());
+
+        let hsl_shift = |c: Color, shift: [f32; 3]| -> Color {
             let (h, s, l) = c.to_hsl();
 
             let (h, s, l) = (
-                (h + 0.0.lerp(&hsl[0], alpha)) % 360.0,
-                (s + 0.0.lerp(&hsl[1], alpha)).clamp(0.0, 100.0),
-                (l + 0.0.lerp(&hsl[2], alpha)).clamp(0.0, 100.0),
+                (h + shift[0]) % 360.0,
+                (s + shift[1]).clamp(0.0, 100.0),
+                (l + shift[2]).clamp(0.0, 100.0),
             );
 
             HslConvertable::from_hsl(h, s, l)
         };
 
+        let shift_color = |c: Color, (shift, blend): ([f32; 3], f32)| {
+            space.lerp(&c, &hsl_shift(c, shift), blend)
+        };
+
         for (_, cell) in cell_iter {
-            if let Some(hsl_mod) = self.hsl_mod_fg {
-                let fg = fg_mapper.map(cell.fg, alpha, |c| hsl_lerp(c, hsl_mod));
+            if let Some(shift) = shift_fg {
+                let fg = fg_mapper.map(cell.fg, alpha, |c| shift_color(resolve(c, false), shift));
                 cell.set_fg(fg);
             }
-            if let Some(hsl_mod) = self.hsl_mod_bg {
-                let bg = bg_mapper.map(cell.bg, alpha, |c| hsl_lerp(c, hsl_mod));
+            if let Some(shift) = shift_bg {
+                let bg = bg_mapper.map(cell.bg, alpha, |c| shift_color(resolve(c, true), shift));
                 cell.set_bg(bg);
             }
 
         }
     }
-
-    fn done(&self) -> bool {
-        self.lifetime.done()
-    }
-
-    fn clone_box(&self) -> Box<dyn Shader> {
-        Box::new(self.clone())
-    }
-
-    fn area(&self) -> Option<Rect> { self.area }
-    fn set_area(&mut self, area: Rect) {
-        self.area = Some(area);
-    }
-
-    fn set_cell_selection(&mut self, strategy: CellFilter) {
-        self.cell_filter = strategy;
-    }
-
-    fn reverse(&mut self) {
-        self.lifetime = self.lifetime.reversed();
-    }
-
-    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
-        Some(&mut self.lifetime)
-    }
-
-    fn cell_selection(&self) -> Option<CellFilter> {
-        Some(self.cell_filter.clone())
-    }
 }
 
 impl From<HslShiftBuilder> for Effect {
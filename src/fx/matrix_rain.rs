@@ -0,0 +1,210 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use rand::prelude::{SeedableRng, SmallRng};
+use rand::Rng;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::color_space::global_color_space;
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::noise::hash;
+use crate::rng::default_rng;
+use crate::{ColorSpace, Shader};
+
+/// A column of glyphs falling through the area.
+#[derive(Clone, Debug)]
+struct Drop {
+    column: u16,
+    /// The row of the head, relative to the area; negative before entering it.
+    head: f32,
+    /// Rows per second.
+    speed: f32,
+    trail_length: u16,
+    seed: u64,
+}
+
+/// Columns of glyphs raining down the area, each with a bright head and a trail fading
+/// into the background. The rain never completes, making it suitable as an ambient
+/// background for lock screens and screensavers; combine it with a [CellFilter] such as
+/// [CellFilter::Empty] to keep it behind the content.
+///
+/// The rain adapts to the area on every frame, so it survives the terminal being resized.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{CellFilter, Effect, EffectRenderer};
+/// use tachyonfx::fx::MatrixRain;
+///
+/// let mut rain: Effect = MatrixRain::builder()
+///     .glyphs("01")
+///     .speed(4.0..12.0)
+///     .density(0.8)
+///     .head_color(Color::White)
+///     .trail_color(Color::LightBlue)
+///     .seed(7)
+///     .selection(CellFilter::Empty)
+///     .into();
+///
+/// // shrinking the area between frames is fine
+/// for area in [Rect::new(0, 0, 40, 12), Rect::new(0, 0, 8, 3)] {
+///     let mut buf = Buffer::empty(area);
+///     buf.render_effect(&mut rain, area, Duration::from_millis(100));
+/// }
+/// ```
+#[derive(Builder, Clone, Debug, Shader)]
+#[builder(pattern = "owned")]
+#[shader(process, done)]
+pub struct MatrixRain {
+    /// The glyphs the drops are made of.
+    #[builder(default = "DEFAULT_GLYPHS.chars().collect()", setter(custom))]
+    glyphs: Vec<char>,
+    /// The range of speeds of the drops, in rows per second.
+    #[builder(default = "6.0..18.0")]
+    speed: Range<f32>,
+    /// The range of trail lengths, in rows.
+    #[builder(default = "4..12")]
+    trail_length: Range<u16>,
+    /// The number of drops relative to the width of the area.
+    #[builder(default = "0.5")]
+    density: f32,
+    #[builder(default = "Color::from_u32(0xe0ffe0)")]
+    head_color: Color,
+    /// The color at the start of the trail, fading into the background color of the cells.
+    #[builder(default = "Color::from_u32(0x00c040)")]
+    trail_color: Color,
+    #[builder(default = "default_rng()")]
+    rng: SmallRng,
+    #[builder(default)]
+    #[shader(filter)]
+    selection: CellFilter,
+    #[builder(setter(skip))]
+    drops: Vec<Drop>,
+    #[builder(default)]
+    #[shader(area)]
+    area: Option<Rect>,
+    #[builder(default)]
+    #[shader(color_space)]
+    color_space: Option<ColorSpace>,
+}
+
+const DEFAULT_GLYPHS: &str = "ｦｱｳｴｵｶｷｹｺｻｼｽｾｿﾀﾂﾃﾅﾆﾇﾈﾊﾋﾎﾏﾐﾑﾒﾓﾔﾕﾗﾘﾜ0123456789:.=*+-<>";
+
+impl MatrixRainBuilder {
+    /// Sets the glyphs the drops are made of.
+    pub fn glyphs(mut self, glyphs: &str) -> Self {
+        self.glyphs = Some(glyphs.chars().collect());
+        self
+    }
+
+    /// Seeds the random number generator, making the rain deterministic. Without a
+    /// seed or explicit `rng`, the default random number generator is used; see
+    /// [`rng`](crate::rng).
+    pub fn seed(self, seed: u64) -> Self {
+        self.rng(SmallRng::seed_from_u64(seed))
+    }
+}
+
+impl From<MatrixRainBuilder> for Effect {
+    fn from(value: MatrixRainBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl MatrixRain {
+    pub fn builder() -> MatrixRainBuilder { MatrixRainBuilder::default() }
+
+    fn ensure_population(&mut self, area: Rect) {
+        let population = (area.width as f32 * self.density).round() as usize;
+        while self.drops.len() < population {
+            let drop = self.spawn_drop(area);
+            self.drops.push(drop);
+        }
+    }
+
+    /// Creates a new drop above the area, staggered by up to the height of the area so
+    /// that the rain doesn't fall as a single wave.
+    fn spawn_drop(&mut self, area: Rect) -> Drop {
+        let trail_length = match self.trail_length.is_empty() {
+            true  => self.trail_length.start,
+            false => self.rng.gen_range(self.trail_length.clone()),
+        };
+        let speed = match self.speed.is_empty() {
+            true  => self.speed.start,
+            false => self.rng.gen_range(self.speed.clone()),
+        };
+        Drop {
+            column: self.rng.gen_range(0..area.width),
+            head: -(self.rng.gen_range(0..area.height) as f32),
+            speed,
+            trail_length,
+            seed: self.rng.gen(),
+        }
+    }
+
+    fn glyph(&self, drop: &Drop, row: u16) -> char {
+        let idx = hash(drop.column as i32, row as i32, drop.seed) * self.glyphs.len() as f32;
+        self.glyphs.get(idx as usize).copied().unwrap_or(' ')
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        // effects may be translated or resized beyond the edges of the buffer
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return None;
+        }
+
+        // remove drops that fell out of the area, or from columns lost to resizing
+        let dt = duration.as_secs_f32();
+        self.drops.iter_mut().for_each(|drop| drop.head += drop.speed * dt);
+        self.drops.retain(|drop| {
+            drop.column < area.width && drop.head - (drop.trail_length as f32) < area.height as f32
+        });
+        self.ensure_population(area);
+
+        let selector = self.selection.selector(area);
+        let space = self.color_space.unwrap_or_else(global_color_space);
+
+        for drop in self.drops.iter() {
+            let head = drop.head.floor() as i32;
+            for i in 0..=drop.trail_length as i32 {
+                let row = head - i;
+                if row < 0 || row >= area.height as i32 {
+                    continue;
+                }
+
+                let pos = Position::new(area.x + drop.column, area.y + row as u16);
+                let cell = buf.get_mut(pos.x, pos.y);
+                if !selector.is_valid(pos, cell) {
+                    continue;
+                }
+
+                let fg = match i {
+                    0 => self.head_color,
+                    _ => {
+                        let alpha = i as f32 / (drop.trail_length as f32 + 1.0);
+                        space.lerp_bg(&self.trail_color, &cell.bg, alpha)
+                    },
+                };
+                cell.set_char(self.glyph(drop, row as u16)).set_fg(fg);
+            }
+        }
+
+        None
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+}
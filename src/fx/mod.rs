@@ -1,5 +1,6 @@
 use std::time::Duration;
-use ratatui::style::Color;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Style};
 use crate::effect::{Effect, IntoEffect};
 use crate::effect_timer::EffectTimer;
 use crate::fx::ansi256::Ansi256;
@@ -8,15 +9,27 @@ use crate::fx::containers::{ParallelEffect, SequentialEffect};
 use crate::fx::dissolve::Dissolve;
 use crate::fx::fade::FadeColors;
 use crate::fx::never_complete::NeverComplete;
+use crate::fx::patterned::PatternedEffect;
+use crate::fx::quantize::QuantizeColors;
 use crate::fx::resize::ResizeArea;
 use crate::fx::repeat::Repeat;
 use crate::fx::sleep::Sleep;
+use crate::fx::slide::Slide;
 use crate::fx::sweep_in::SweepIn;
 use crate::fx::temporary::{IntoTemporaryEffect, TemporaryEffect};
+use crate::fx::transition::Transition;
+use crate::keyframes::Keyframes;
+use crate::{ColorDepth, Gradient, Interpolatable, Interpolation, Pattern};
 
 pub use glitch::Glitch;
+pub use matrix_rain::MatrixRain;
+pub use noise_field::{NoiseField, NoiseKind, NoiseTarget};
+pub use particles::{Emitter, EmitterBuilder, ParticleSystem, ParticleSystemBuilder};
+pub use repeat::RepeatMode;
+pub use shatter::Shatter;
 use hsl_shift::HslShift;
-pub use sweep_in::Direction;
+pub use sweep_in::{Direction, SweepShape};
+pub use typewriter::Typewriter;
 
 mod ansi256;
 mod consume_tick;
@@ -24,14 +37,23 @@ mod containers;
 mod dissolve;
 mod fade;
 mod glitch;
+mod matrix_rain;
 mod never_complete;
+mod noise_field;
+mod particles;
+mod patterned;
 mod ping_pong;
+mod quantize;
 mod repeat;
 mod resize;
+mod shatter;
+mod slide;
 mod sleep;
 mod sweep_in;
 mod temporary;
+mod transition;
 mod translate;
+mod typewriter;
 mod hsl_shift;
 
 use ping_pong::PingPong;
@@ -58,11 +80,185 @@ pub fn hsl_shift_fg<T: Into<EffectTimer>>(
     hsl_shift(Some(hsl_fg_change), None, lifetime)
 }
 
+/// Shifts the hue, saturation, and lightness of the foreground and background colors
+/// through the keyframes; the shift is zero at the start, and the effect lasts until
+/// the last stop.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, Interpolation::*, Keyframes};
+///
+/// // flash the text red-shifted and brighter, then settle on a subtle tint
+/// let shift = Keyframes::new()
+///     .stop(150, [-30.0, 20.0, 30.0], QuadOut)
+///     .stop(600, [-10.0, 0.0, 5.0], SineInOut);
+///
+/// let effect = fx::hsl_shift_keyframes(Some(shift), None);
+/// ```
+pub fn hsl_shift_keyframes(
+    hsl_fg_keyframes: Option<Keyframes<[f32; 3]>>,
+    hsl_bg_keyframes: Option<Keyframes<[f32; 3]>>,
+) -> Effect {
+    let duration = keyframes_duration(hsl_fg_keyframes.as_ref(), hsl_bg_keyframes.as_ref());
+    HslShift::builder()
+        .hsl_keyframes_fg(hsl_fg_keyframes)
+        .hsl_keyframes_bg(hsl_bg_keyframes)
+        .lifetime(EffectTimer::new(duration, Interpolation::Linear))
+        .into()
+}
+
 /// Returns an effect that downsamples to 256 color mode.
 pub fn term256_colors() -> Effect {
     Ansi256::default().into_effect()
 }
 
+/// Returns an effect that quantizes colors to the color depth, optionally with ordered
+/// dithering to reduce the banding of gradients. Colors which can be displayed at the
+/// color depth are left untouched. To quantize the output of all effects, set the
+/// [global color depth](crate::set_global_color_depth) instead. The effect never completes.
+///
+/// # Arguments
+/// * `depth` - The color depth to quantize to.
+/// * `dither` - Whether to apply a 4x4 ordered dither.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, ColorDepth, Interpolation};
+/// use tachyonfx::fx::Direction;
+/// use ratatui::style::Color;
+///
+/// let gradient = fx::sweep_in(Direction::LeftToRight, 10, Color::Black, (500, Interpolation::Linear));
+/// fx::parallel(vec![gradient, fx::quantize_colors(ColorDepth::Ansi16, true)]);
+/// ```
+pub fn quantize_colors(depth: ColorDepth, dither: bool) -> Effect {
+    QuantizeColors::new(depth, dither).into_effect()
+}
+
+/// Plays the effect through a spatial pattern: rather than every cell progressing at
+/// once, each cell is blended from the start to the end state of the effect according
+/// to its alpha in the pattern. The effect's own timer only determines the duration and
+/// easing; its start and end states are sampled on every frame, so it should have a
+/// finite duration. Hooks of the effect, such as [on_complete](Effect::on_complete),
+/// fire along with the patterned effect.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation, Pattern};
+///
+/// // fade in from the center
+/// let fade = fx::fade_from(Color::Black, Color::Black, (600, Interpolation::QuadOut));
+/// fx::with_pattern(Pattern::radial((0.5, 0.5)), fade);
+///
+/// // dissolve in a checkerboard
+/// fx::with_pattern(Pattern::checkerboard(2), fx::dissolve(1, 400));
+/// ```
+pub fn with_pattern(pattern: Pattern, mut effect: Effect) -> Effect {
+    // the effect is sampled at its start and end on every frame, firing any hooks
+    let hooks = effect.take_hooks();
+    PatternedEffect::new(pattern, effect).into_effect().with_hooks(hooks)
+}
+
+/// Paints animated flames, rising from the bottom of the area, to the background of the
+/// cells. Restrict the effect to the empty cells with a [CellFilter](crate::CellFilter)
+/// to keep the content legible; see [NoiseField] for more options.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, CellFilter};
+///
+/// fx::never_complete(fx::fire(42, 1000))
+///     .with_cell_selection(CellFilter::Empty);
+/// ```
+pub fn fire<T: Into<EffectTimer>>(seed: u64, lifetime: T) -> Effect {
+    noise_field(NoiseKind::Fire, seed, lifetime)
+}
+
+/// Paints drifting plasma to the background of the cells; see [fire()].
+pub fn plasma<T: Into<EffectTimer>>(seed: u64, lifetime: T) -> Effect {
+    noise_field(NoiseKind::Plasma, seed, lifetime)
+}
+
+/// Paints slowly drifting clouds to the background of the cells; see [fire()].
+pub fn clouds<T: Into<EffectTimer>>(seed: u64, lifetime: T) -> Effect {
+    noise_field(NoiseKind::Clouds, seed, lifetime)
+}
+
+fn noise_field<T: Into<EffectTimer>>(kind: NoiseKind, seed: u64, lifetime: T) -> Effect {
+    NoiseField::builder()
+        .kind(kind)
+        .seed(seed)
+        .lifetime(lifetime.into())
+        .into()
+}
+
+/// Blows the content of the area apart: every glyph flies outward from the center of the
+/// area, carrying its style along and clearing its original location. See [Shatter] to
+/// change the center, speeds and gravity.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, Interpolation};
+///
+/// // dismiss a notification
+/// let dismiss = fx::explode(42, (600, Interpolation::QuadIn));
+/// ```
+pub fn explode<T: Into<EffectTimer>>(seed: u64, lifetime: T) -> Effect {
+    Shatter::builder()
+        .seed(seed)
+        .lifetime(lifetime.into())
+        .into()
+}
+
+/// The reverse of [explode()]: scattered glyphs fly into place, assembling the content.
+pub fn assemble<T: Into<EffectTimer>>(seed: u64, lifetime: T) -> Effect {
+    explode(seed, lifetime.into().reversed())
+}
+
+/// Rains down columns of glyphs with fading trails, indefinitely; see [MatrixRain] to
+/// configure the glyphs, speed, density and colors.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, CellFilter};
+///
+/// let screensaver = fx::matrix_rain(42).with_cell_selection(CellFilter::Empty);
+/// ```
+pub fn matrix_rain(seed: u64) -> Effect {
+    MatrixRain::builder()
+        .seed(seed)
+        .into()
+}
+
+/// Runs a [ParticleSystem] with the emitters, completing once the emitters have stopped
+/// and the last particle has died.
+///
+/// Particles have no `FxDescriptor` or [DSL](crate::dsl) counterpart: each emitter takes
+/// over a dozen optional parameters, which are only practical to set with an
+/// [EmitterBuilder].
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx::{self, Emitter};
+///
+/// // a short burst of sparks from the middle of the area
+/// let sparks = Emitter::builder()
+///     .burst(30)
+///     .speed(10.0..30.0)
+///     .drag(2.0)
+///     .lifetime_ms(300..600)
+///     .glyphs("*+·")
+///     .colors(vec![Color::White, Color::Yellow, Color::Red]);
+///
+/// fx::particles(vec![sparks.into()]);
+/// ```
+pub fn particles(emitters: Vec<Emitter>) -> Effect {
+    emitters.into_iter()
+        .fold(ParticleSystem::builder(), |system, emitter| system.emitter(emitter))
+        .into()
+}
+
 /// Repeat the effect indefinitely or for a specified number of times or duration.
 pub fn repeat(effect: Effect, mode: repeat::RepeatMode) -> Effect {
     Repeat::new(effect, mode).into_effect()
@@ -85,13 +281,28 @@ pub fn sweep_out<T: Into<EffectTimer>, C: Into<Color>>(
     faded_color: C,
     lifetime: T,
 ) -> Effect {
-    let flipped = match direction {
-        Direction::LeftToRight => Direction::RightToLeft,
-        Direction::RightToLeft => Direction::LeftToRight,
-        Direction::UpToDown => Direction::DownToUp,
-        Direction::DownToUp => Direction::UpToDown,
-    };
-    sweep_in(flipped, gradient_length, faded_color, lifetime).reversed()
+    sweep_in(direction.flipped(), gradient_length, faded_color, lifetime).reversed()
+}
+
+/// Sweeps out to the specified color, with the sweep following the shape, e.g. closing
+/// like an iris or wiping around like the hand of a clock.
+pub fn sweep_out_shape<T: Into<EffectTimer>, C: Into<Color>>(
+    shape: SweepShape,
+    gradient_length: u16,
+    faded_color: C,
+    lifetime: T,
+) -> Effect {
+    sweep_in_shape(shape.flipped(), gradient_length, faded_color, lifetime).reversed()
+}
+
+/// Sweeps out to the colors of the gradient, laid out over the effect's area.
+pub fn sweep_out_gradient<T: Into<EffectTimer>>(
+    direction: Direction,
+    gradient_length: u16,
+    gradient: Gradient,
+    lifetime: T,
+) -> Effect {
+    sweep_in_gradient(direction.flipped(), gradient_length, gradient, lifetime).reversed()
 }
 
 /// Sweeps in a from the specified color.
@@ -105,6 +316,89 @@ pub fn sweep_in<T: Into<EffectTimer>, C: Into<Color>>(
         .into_effect()
 }
 
+/// Sweeps in from the specified color, with the sweep following the shape: along a line
+/// at any angle, radially like an iris, or around a center like the hand of a clock.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+/// use tachyonfx::fx::SweepShape;
+///
+/// let iris = SweepShape::IrisOpen { center: (0.5, 0.5) };
+/// fx::sweep_in_shape(iris, 10, Color::Black, (500, Interpolation::QuadOut));
+///
+/// let diagonal = SweepShape::Linear { angle: 45.0 };
+/// fx::sweep_in_shape(diagonal, 10, Color::Black, (500, Interpolation::QuadOut));
+/// ```
+pub fn sweep_in_shape<T: Into<EffectTimer>, C: Into<Color>>(
+    shape: SweepShape,
+    gradient_length: u16,
+    faded_color: C,
+    lifetime: T,
+) -> Effect {
+    SweepIn::with_shape(shape, gradient_length, faded_color.into(), lifetime.into())
+        .into_effect()
+}
+
+/// Sweeps in from the colors of the gradient, laid out over the effect's area.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Gradient, Interpolation};
+/// use tachyonfx::fx::Direction;
+///
+/// let sunset = Gradient::linear(90.0)
+///     .stop(0.0, Color::Rgb(0x45, 0x2a, 0x6e))
+///     .stop(0.6, Color::Rgb(0xe0, 0x5a, 0x47))
+///     .stop(1.0, Color::Rgb(0xf9, 0xc7, 0x4f));
+///
+/// fx::sweep_in_gradient(Direction::LeftToRight, 10, sunset, (800, Interpolation::QuadOut));
+/// ```
+pub fn sweep_in_gradient<T: Into<EffectTimer>>(
+    direction: Direction,
+    gradient_length: u16,
+    gradient: Gradient,
+    lifetime: T,
+) -> Effect {
+    SweepIn::new(direction, gradient_length, Color::Reset, lifetime.into())
+        .with_faded_gradient(gradient)
+        .into_effect()
+}
+
+/// Slides the rendered content of the area into place, starting displaced by `from`
+/// cells. Cells not covered by the content are cleared to the `fill` style. Unlike
+/// [translate()], which moves the area of another effect, the content itself is moved;
+/// combine it with other effects in a [parallel()] or [sequence()].
+///
+/// # Example
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::{fx, Interpolation};
+///
+/// // a sidebar sliding in from the left, overshooting before settling
+/// let fill = Style::default().bg(Color::Black);
+/// fx::slide_in((-30, 0), fill, (500, Interpolation::BackOut));
+/// ```
+pub fn slide_in<T: Into<EffectTimer>>(
+    from: (i16, i16),
+    fill: Style,
+    lifetime: T,
+) -> Effect {
+    Slide::new(from, (0, 0), fill, lifetime.into()).into_effect()
+}
+
+/// Slides the rendered content of the area out of place, ending displaced by `to` cells;
+/// see [slide_in()].
+pub fn slide_out<T: Into<EffectTimer>>(
+    to: (i16, i16),
+    fill: Style,
+    lifetime: T,
+) -> Effect {
+    Slide::new((0, 0), to, fill, lifetime.into()).into_effect()
+}
+
 pub fn translate<T: Into<EffectTimer>>(
     fx: Option<Effect>,
     translate_by: (i16, i16),
@@ -144,6 +438,23 @@ pub fn dissolve<T: Into<EffectTimer>>(cycle_len: usize, lifetime: T) -> Effect {
         .into_effect()
 }
 
+/// Types out the text one character at a time in reading order, skipping over runs of
+/// whitespace; reverse the effect to backspace instead. See [Typewriter] for a caret and
+/// per-character jitter.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, CellFilter};
+///
+/// let typing = fx::typewriter(800).with_cell_selection(CellFilter::Text);
+/// let backspacing = fx::typewriter(400).reversed();
+/// ```
+pub fn typewriter<T: Into<EffectTimer>>(lifetime: T) -> Effect {
+    Typewriter::builder()
+        .lifetime(lifetime.into())
+        .into()
+}
+
 /// The reverse of [dissolve()].
 pub fn coalesce<T: Into<EffectTimer>>(cycle_len: usize, lifetime: T) -> Effect {
     let lifetime = lifetime.into().reversed();
@@ -151,6 +462,19 @@ pub fn coalesce<T: Into<EffectTimer>>(cycle_len: usize, lifetime: T) -> Effect {
         .into_effect()
 }
 
+/// Like [dissolve()], but with a deterministic dissolve pattern for the given `seed`.
+pub fn dissolve_seeded<T: Into<EffectTimer>>(cycle_len: usize, seed: u64, lifetime: T) -> Effect {
+    Dissolve::with_seed(lifetime.into(), cycle_len, seed)
+        .into_effect()
+}
+
+/// Like [coalesce()], but with a deterministic coalesce pattern for the given `seed`.
+pub fn coalesce_seeded<T: Into<EffectTimer>>(cycle_len: usize, seed: u64, lifetime: T) -> Effect {
+    let lifetime = lifetime.into().reversed();
+    Dissolve::with_seed(lifetime, cycle_len, seed)
+        .into_effect()
+}
+
 
 /// Fades the foreground color to the specified color over the specified duration.
 pub fn fade_to_fg<T: Into<EffectTimer>, C: Into<Color>>(
@@ -186,6 +510,134 @@ pub fn fade_from<T: Into<EffectTimer>, C: Into<Color>>(
     fade(Some(fg), Some(bg), lifetime.into(), true)
 }
 
+/// Fades the foreground and/or background colors to the gradients, laid out over the
+/// effect's area. With a zero duration, the gradients are painted right away.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Gradient};
+///
+/// let rainbow = Gradient::angular((0.5, 0.5), 0.0)
+///     .stop(0.0, Color::Rgb(255, 0, 0))
+///     .stop(0.5, Color::Rgb(0, 255, 255))
+///     .stop(1.0, Color::Rgb(255, 0, 0));
+///
+/// fx::fade_to_gradient(Some(rainbow), None, 500);
+/// ```
+pub fn fade_to_gradient<T: Into<EffectTimer>>(
+    fg: Option<Gradient>,
+    bg: Option<Gradient>,
+    lifetime: T,
+) -> Effect {
+    fade_gradient(fg, bg, lifetime.into(), false)
+}
+
+/// Fades the foreground and/or background colors from the gradients, laid out over the
+/// effect's area.
+pub fn fade_from_gradient<T: Into<EffectTimer>>(
+    fg: Option<Gradient>,
+    bg: Option<Gradient>,
+    lifetime: T,
+) -> Effect {
+    fade_gradient(fg, bg, lifetime.into(), true)
+}
+
+
+/// Fades the foreground color through the keyframes, starting from each cell's
+/// color. The effect lasts until the last stop.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation::*, Keyframes};
+///
+/// // flash to white, hold, then fade out to the background
+/// let flash = Keyframes::new()
+///     .stop(100, Color::White, QuadOut)
+///     .stop(300, Color::White, Linear)
+///     .stop(800, Color::Black, SineIn);
+///
+/// let effect = fx::fade_fg_keyframes(flash);
+/// ```
+pub fn fade_fg_keyframes(fg: Keyframes<Color>) -> Effect {
+    fade_keyframes(Some(fg), None)
+}
+
+/// Fades the foreground and background colors through their keyframes, starting
+/// from each cell's colors. The effect lasts until the last stop of either.
+pub fn fade_keyframes(
+    fg: Option<Keyframes<Color>>,
+    bg: Option<Keyframes<Color>>,
+) -> Effect {
+    let duration = keyframes_duration(fg.as_ref(), bg.as_ref());
+    FadeColors::builder()
+        .fg(None)
+        .bg(None)
+        .fg_keyframes(fg)
+        .bg_keyframes(bg)
+        .lifetime(EffectTimer::new(duration, Interpolation::Linear))
+        .into()
+}
+
+/// Cross-fades from the `from` buffer, typically the previous screen, to the
+/// buffer being processed. The text of the old screen fades out over the first
+/// half of the transition, and the new text fades in over the second half.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use tachyonfx::{fx, Shader};
+///
+/// let before = Buffer::with_lines(["page 1"]);
+/// let mut effect = fx::cross_fade(before.clone(), 500);
+///
+/// // the new screen has just been rendered
+/// let mut buf = Buffer::with_lines(["page 2"]);
+/// let area = buf.area;
+/// effect.process(Duration::from_millis(100), &mut buf, area);
+/// assert_eq!(buf.get(5, 0).symbol(), "1");
+/// ```
+pub fn cross_fade<T: Into<EffectTimer>>(from: Buffer, lifetime: T) -> Effect {
+    Transition::cross_fade(from, lifetime.into())
+        .into_effect()
+}
+
+/// Dissolves the `from` buffer, typically the previous screen, into the buffer
+/// being processed. The `cycle_len` parameter works as in [dissolve()].
+pub fn dissolve_transition<T: Into<EffectTimer>>(
+    from: Buffer,
+    cycle_len: usize,
+    lifetime: T,
+) -> Effect {
+    Transition::dissolve(from, cycle_len, lifetime.into())
+        .into_effect()
+}
+
+/// Like [dissolve_transition()], but with a deterministic dissolve pattern for the
+/// given `seed`.
+pub fn dissolve_transition_seeded<T: Into<EffectTimer>>(
+    from: Buffer,
+    cycle_len: usize,
+    seed: u64,
+    lifetime: T,
+) -> Effect {
+    Transition::dissolve_seeded(from, cycle_len, seed, lifetime.into())
+        .into_effect()
+}
+
+/// Slides the `from` buffer, typically the previous screen, out of the area in
+/// the specified direction, while pushing in the buffer being processed. Cells left
+/// uncovered by overshooting interpolations, such as `BackOut`, are reset.
+pub fn slide_transition<T: Into<EffectTimer>>(
+    from: Buffer,
+    direction: Direction,
+    lifetime: T,
+) -> Effect {
+    Transition::slide(from, direction, lifetime.into())
+        .into_effect()
+}
 
 /// Pauses for the specified duration.
 pub fn sleep<T: Into<EffectTimer>>(duration: T) -> Effect {
@@ -217,6 +669,13 @@ pub fn timed_never_complete(duration: Duration, effect: Effect) -> Effect {
 }
 
 
+fn keyframes_duration<T>(a: Option<&Keyframes<T>>, b: Option<&Keyframes<T>>) -> Duration
+    where T: Interpolatable<T> + Clone
+{
+    let duration = |kf: Option<&Keyframes<T>>| kf.map_or(Duration::ZERO, Keyframes::duration);
+    duration(a).max(duration(b))
+}
+
 fn fade<C: Into<Color>>(
     fg: Option<C>,
     bg: Option<C>,
@@ -229,3 +688,18 @@ fn fade<C: Into<Color>>(
         .lifetime(if reverse { lifetime.reversed() } else { lifetime })
         .into()
 }
+
+fn fade_gradient(
+    fg: Option<Gradient>,
+    bg: Option<Gradient>,
+    lifetime: EffectTimer,
+    reverse: bool,
+) -> Effect {
+    FadeColors::builder()
+        .fg(None)
+        .bg(None)
+        .fg_gradient(fg)
+        .bg_gradient(bg)
+        .lifetime(if reverse { lifetime.reversed() } else { lifetime })
+        .into()
+}
@@ -1,7 +1,7 @@
 use std::time::Duration;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use crate::{CellIterator, EffectTimer};
+use crate::{CellIterator, ColorSpace, EffectTimer};
 use crate::effect::{Effect, CellFilter};
 use crate::shader::Shader;
 
@@ -35,6 +35,10 @@ impl Shader for NeverComplete {
         self.effect.set_cell_selection(strategy);
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.effect.set_color_space(color_space);
+    }
+
     fn reverse(&mut self) {
         self.effect.reverse()
     }
@@ -46,4 +50,16 @@ impl Shader for NeverComplete {
     fn cell_selection(&self) -> Option<CellFilter> {
         self.effect.cell_selection()
     }
+
+    fn duration(&self) -> Option<Duration> {
+        None
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.effect.seek(elapsed)
+    }
+
+    fn loop_count(&self) -> u32 {
+        self.effect.loop_count()
+    }
 }
\ No newline at end of file
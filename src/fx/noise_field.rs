@@ -0,0 +1,208 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use rand::Rng;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::color_space::global_color_space;
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::gradient::CELL_ASPECT;
+use crate::noise::{fbm, perlin};
+use crate::rng::default_rng;
+use crate::{ColorSpace, Gradient, Shader};
+
+/// The procedural content generated by a [NoiseField].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoiseKind {
+    /// Flames rising from the bottom of the area.
+    Fire,
+    /// Interfering waves, warped by noise, drifting through the palette.
+    Plasma,
+    /// Soft clouds drifting from left to right.
+    Clouds,
+}
+
+/// What a [NoiseField] paints with the colors of its palette.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoiseTarget {
+    /// The foreground color.
+    Fg,
+    /// The background color.
+    #[default]
+    Bg,
+    /// Shade glyphs of increasing density (` ░▒▓█`), colored by the foreground.
+    Glyphs,
+}
+
+/// Animated, noise-driven procedural content, such as fire, plasma or clouds, for idle
+/// screens and splash backgrounds. The noise is mapped to the colors of a palette, and
+/// painted to the cells selected by the cell filter, e.g. only the empty cells.
+///
+/// The field keeps animating for the lifetime of the effect; wrap it in
+/// [never_complete](crate::fx::never_complete) to animate indefinitely.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, CellFilter, Effect, Gradient};
+/// use tachyonfx::fx::{NoiseField, NoiseKind, NoiseTarget};
+/// use ratatui::style::Color;
+///
+/// let smoke: Effect = NoiseField::builder()
+///     .kind(NoiseKind::Clouds)
+///     .target(NoiseTarget::Glyphs)
+///     .palette(Gradient::linear(0.0).stop(0.0, Color::DarkGray).stop(1.0, Color::White))
+///     .scale(12.0)
+///     .seed(7)
+///     .lifetime(5000.into())
+///     .into();
+///
+/// let background = fx::never_complete(fx::fire(7, 1000))
+///     .with_cell_selection(CellFilter::Empty);
+/// ```
+#[derive(Builder, Clone, Shader)]
+#[builder(pattern = "owned")]
+#[shader(process, seek)]
+pub struct NoiseField {
+    kind: NoiseKind,
+    /// What to paint; the background by default.
+    #[builder(default)]
+    target: NoiseTarget,
+    /// The colors the noise is mapped to, from `0.0` to `1.0`; defaults to a palette
+    /// matching the kind of noise.
+    #[builder(default, setter(strip_option))]
+    palette: Option<Gradient>,
+    /// The approximate size of the features, in columns.
+    #[builder(default = "8.0")]
+    scale: f32,
+    /// The speed of the animation, relative to the default.
+    #[builder(default = "1.0")]
+    speed: f32,
+    #[builder(default = "default_rng().gen()")]
+    seed: u64,
+    #[builder(setter(skip))]
+    time: Duration,
+    #[shader(timer)]
+    lifetime: EffectTimer,
+    #[builder(default)]
+    #[shader(area)]
+    area: Option<Rect>,
+    #[builder(default)]
+    #[shader(filter)]
+    cell_filter: CellFilter,
+    #[builder(default)]
+    #[shader(color_space)]
+    color_space: Option<ColorSpace>,
+}
+
+impl From<NoiseFieldBuilder> for Effect {
+    fn from(value: NoiseFieldBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl NoiseField {
+    pub fn builder() -> NoiseFieldBuilder { NoiseFieldBuilder::default() }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        self.time += duration;
+        let overflow = self.lifetime.process(duration);
+
+        let palette = self.palette.clone().unwrap_or_else(|| default_palette(self.kind));
+        let space = self.color_space.unwrap_or_else(global_color_space);
+        let time = self.time.as_secs_f32() * self.speed;
+
+        let cell_iter = self.cell_iter(buf, area);
+        cell_iter.for_each(|(pos, cell)| {
+            let intensity = self.intensity(area, pos, time);
+            let color = palette.color_at_with(intensity, |a, b, t| space.lerp(a, b, t));
+
+            match self.target {
+                NoiseTarget::Fg     => { cell.set_fg(color); },
+                NoiseTarget::Bg     => { cell.set_bg(color); },
+                NoiseTarget::Glyphs => { cell.set_char(shade(intensity)).set_fg(color); },
+            }
+        });
+
+        overflow
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.time = elapsed;
+        self.lifetime.set_elapsed(elapsed);
+    }
+
+    /// Returns the intensity of the noise, from `0.0` to `1.0`, at the cell at `pos`.
+    fn intensity(&self, area: Rect, pos: Position, time: f32) -> f32 {
+        let scale = self.scale.max(f32::EPSILON);
+        let x = (pos.x - area.x) as f32 / scale;
+        let y = (pos.y - area.y) as f32 * CELL_ASPECT / scale;
+
+        let intensity = match self.kind {
+            NoiseKind::Fire => {
+                // 1.0 at the bottom of the area, 0.0 at the top
+                let heat = (pos.y - area.y) as f32 / area.height.saturating_sub(1).max(1) as f32;
+                let n = fbm(perlin, x, y + time * 1.5, self.seed, 4);
+                n * 1.6 - (1.0 - heat) * 0.9
+            },
+            NoiseKind::Plasma => {
+                let (wx, wy) = (
+                    x + perlin(x * 0.5, y * 0.5 + time * 0.2, self.seed) * 2.0,
+                    y + perlin(x * 0.5 + 7.3, y * 0.5 - time * 0.2, self.seed) * 2.0,
+                );
+                let waves = (wx * 1.7 + time).sin()
+                    + (wy * 1.3 - time * 0.8).sin()
+                    + ((wx + wy) * 0.9 + time * 0.6).sin()
+                    + (wx.hypot(wy) * 1.1 - time * 1.2).sin();
+
+                (waves / 8.0 + 0.5 + time * 0.05).rem_euclid(1.0)
+            },
+            NoiseKind::Clouds => {
+                let n = fbm(perlin, x - time * 0.4, y + time * 0.1, self.seed, 5);
+                (n - 0.3) / 0.4
+            },
+        };
+
+        intensity.clamp(0.0, 1.0)
+    }
+}
+
+fn shade(intensity: f32) -> char {
+    const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+    SHADES[((intensity * SHADES.len() as f32) as usize).min(SHADES.len() - 1)]
+}
+
+fn default_palette(kind: NoiseKind) -> Gradient {
+    let rgb = |hex: u32| Color::from_u32(hex);
+    let palette = Gradient::linear(0.0);
+
+    match kind {
+        NoiseKind::Fire => palette
+            .stop(0.0, rgb(0x000000))
+            .stop(0.3, rgb(0x7a0b00))
+            .stop(0.55, rgb(0xe24a0c))
+            .stop(0.75, rgb(0xf89d13))
+            .stop(0.9, rgb(0xfce36b))
+            .stop(1.0, rgb(0xfffbe6)),
+        // cyclic, as plasma drifts through the palette
+        NoiseKind::Plasma => palette
+            .stop(0.0, rgb(0x3b0f70))
+            .stop(0.25, rgb(0x1f78b4))
+            .stop(0.5, rgb(0x2ec4b6))
+            .stop(0.75, rgb(0xf9c74f))
+            .stop(1.0, rgb(0x3b0f70)),
+        NoiseKind::Clouds => palette
+            .stop(0.0, rgb(0x1d2021))
+            .stop(0.5, rgb(0x505a6b))
+            .stop(1.0, rgb(0xd5dde8)),
+    }
+}
@@ -0,0 +1,343 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use rand::prelude::{SeedableRng, SmallRng};
+use rand::Rng;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::color_space::{global_color_space, ColorSpace};
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::gradient::{scaled_size, CELL_ASPECT};
+use crate::rng::default_rng;
+use crate::Shader;
+
+/// Spawns the particles of a [ParticleSystem], and describes how they move and look over
+/// their life. Positions and velocities are measured in columns, with rows counting as
+/// two columns, so that particles move at the same speed in every direction.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx::Emitter;
+///
+/// // a fountain of sparks, fading from yellow to red as they fall back down
+/// let sparks = Emitter::builder()
+///     .origin((0.5, 1.0))
+///     .rate(40.0)
+///     .angle(-90.0)
+///     .angle_spread(25.0)
+///     .speed(20.0..35.0)
+///     .gravity(40.0)
+///     .glyphs("*+·")
+///     .colors(vec![Color::Yellow, Color::Red]);
+/// ```
+#[derive(Builder, Clone, Debug)]
+#[builder(pattern = "owned")]
+pub struct Emitter {
+    /// Where particles are spawned, relative to the area; `(0.5, 0.5)` is the middle.
+    #[builder(default = "(0.5, 0.5)")]
+    origin: (f32, f32),
+    /// The width and height, in columns, of the region around the origin in which
+    /// particles are spawned.
+    #[builder(default)]
+    origin_spread: (f32, f32),
+    /// Particles spawned per second.
+    #[builder(default)]
+    rate: f32,
+    /// Particles spawned at once when the emitter starts.
+    #[builder(default)]
+    burst: u32,
+    /// How long the emitter keeps spawning particles at its `rate`; forever if not set.
+    #[builder(default, setter(strip_option))]
+    duration: Option<Duration>,
+    /// The range of lifetimes of the particles, in milliseconds.
+    #[builder(default = "500..1000")]
+    lifetime_ms: Range<u32>,
+    /// The direction particles are launched in, in degrees, clockwise from left-to-right;
+    /// `-90.0` launches them upwards.
+    #[builder(default)]
+    angle: f32,
+    /// The maximum deviation from `angle`, in degrees; `180.0` launches particles in
+    /// every direction.
+    #[builder(default = "180.0")]
+    angle_spread: f32,
+    /// The range of launch speeds, in columns per second.
+    #[builder(default = "5.0..10.0")]
+    speed: Range<f32>,
+    /// The downward acceleration, in columns per second squared.
+    #[builder(default)]
+    gravity: f32,
+    /// How quickly particles slow down; the fraction of the velocity lost per second
+    /// approaches `1 - e^(-drag)`.
+    #[builder(default)]
+    drag: f32,
+    /// The glyphs of a particle over its life, from birth to death.
+    #[builder(default = "vec!['*']", setter(custom))]
+    glyphs: Vec<char>,
+    /// The colors of a particle over its life, evenly spaced from birth to death and
+    /// interpolated in the color space of the [ParticleSystem].
+    #[builder(default = "vec![Color::White]")]
+    colors: Vec<Color>,
+
+    #[builder(setter(skip))]
+    elapsed: Duration,
+    #[builder(setter(skip))]
+    pending: f32,
+    #[builder(setter(skip))]
+    burst_emitted: bool,
+}
+
+#[derive(Clone, Debug)]
+struct Particle {
+    emitter: usize,
+    position: (f32, f32),
+    velocity: (f32, f32),
+    age: f32,
+    lifetime: f32,
+}
+
+/// A system of particles moving independently of the cells of the buffer, such as sparks,
+/// confetti or debris. Particles are spawned by [Emitter]s, and drawn into the cells their
+/// positions round to; only cells selected by the cell filter are painted.
+///
+/// The system is done once all emitters have stopped spawning particles and the last
+/// particle has died.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::Effect;
+/// use tachyonfx::fx::{Emitter, ParticleSystem};
+///
+/// // confetti, bursting from the bottom of the area and raining down
+/// let confetti = |color| Emitter::builder()
+///     .origin((0.5, 1.0))
+///     .burst(40)
+///     .angle(-90.0)
+///     .angle_spread(40.0)
+///     .speed(25.0..45.0)
+///     .gravity(30.0)
+///     .drag(0.8)
+///     .lifetime_ms(1200..2000)
+///     .glyphs("▪▫")
+///     .colors(vec![color]);
+///
+/// let effect: Effect = ParticleSystem::builder()
+///     .emitter(confetti(Color::LightRed))
+///     .emitter(confetti(Color::LightYellow))
+///     .emitter(confetti(Color::LightBlue))
+///     .seed(7)
+///     .into();
+/// ```
+#[derive(Builder, Clone, Debug, Shader)]
+#[builder(pattern = "owned")]
+#[shader(process, done)]
+pub struct ParticleSystem {
+    #[builder(default, setter(custom))]
+    emitters: Vec<Emitter>,
+    #[builder(default = "default_rng()")]
+    rng: SmallRng,
+    #[builder(setter(skip))]
+    particles: Vec<Particle>,
+    #[builder(default)]
+    #[shader(filter)]
+    selection: CellFilter,
+    #[builder(default)]
+    #[shader(area)]
+    area: Option<Rect>,
+    /// The color space the colors of the particles are interpolated in; the
+    /// [global color space](crate::global_color_space) if not set.
+    #[builder(default)]
+    #[shader(color_space)]
+    color_space: Option<ColorSpace>,
+}
+
+impl EmitterBuilder {
+    /// Sets the glyphs of a particle over its life, from birth to death.
+    pub fn glyphs(mut self, glyphs: &str) -> Self {
+        self.glyphs = Some(glyphs.chars().collect());
+        self
+    }
+}
+
+impl From<EmitterBuilder> for Emitter {
+    fn from(value: EmitterBuilder) -> Self {
+        value.build().unwrap()
+    }
+}
+
+impl Emitter {
+    pub fn builder() -> EmitterBuilder { EmitterBuilder::default() }
+
+    /// Returns the number of particles to spawn at once, and the number of particles to
+    /// spawn over the course of the next `duration`.
+    fn spawn_count(&mut self, duration: Duration) -> (u32, u32) {
+        let burst = if self.burst_emitted { 0 } else { self.burst };
+        self.burst_emitted = true;
+
+        let active = match self.duration {
+            Some(d) => d.saturating_sub(self.elapsed).min(duration),
+            None    => duration,
+        };
+        self.elapsed += duration;
+
+        self.pending += self.rate.max(0.0) * active.as_secs_f32();
+        let spawned = self.pending.floor();
+        self.pending -= spawned;
+
+        (burst, spawned as u32)
+    }
+
+    /// Whether the emitter has stopped spawning particles.
+    fn exhausted(&self) -> bool {
+        self.burst_emitted
+            && (self.rate <= 0.0 || self.duration.is_some_and(|d| self.elapsed >= d))
+    }
+
+    fn spawn(&self, emitter: usize, area: Rect, rng: &mut SmallRng) -> Particle {
+        let (w, h) = scaled_size(area);
+        let mut offset = |spread: f32| match spread > 0.0 {
+            true  => rng.gen_range(-spread..spread) / 2.0,
+            false => 0.0,
+        };
+        let position = (
+            self.origin.0 * w + offset(self.origin_spread.0),
+            self.origin.1 * h + offset(self.origin_spread.1),
+        );
+
+        let angle = self.angle + offset(self.angle_spread * 2.0);
+        let speed = sample(rng, &self.speed);
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let lifetime = sample(rng, &(self.lifetime_ms.start as f32..self.lifetime_ms.end as f32));
+
+        Particle {
+            emitter,
+            position,
+            velocity: (cos * speed, sin * speed),
+            age: 0.0,
+            lifetime: lifetime / 1000.0,
+        }
+    }
+
+    /// Advances the particle by `dt` seconds.
+    fn step(&self, particle: &mut Particle, dt: f32) {
+        let damping = (-self.drag.max(0.0) * dt).exp();
+
+        particle.velocity.1 += self.gravity * dt;
+        particle.velocity = (particle.velocity.0 * damping, particle.velocity.1 * damping);
+        particle.position.0 += particle.velocity.0 * dt;
+        particle.position.1 += particle.velocity.1 * dt;
+        particle.age += dt;
+    }
+
+    fn glyph_at(&self, life: f32) -> char {
+        let idx = (life * self.glyphs.len() as f32) as usize;
+        self.glyphs.get(idx.min(self.glyphs.len().saturating_sub(1))).copied().unwrap_or(' ')
+    }
+
+    fn color_at(&self, life: f32, space: ColorSpace) -> Color {
+        match self.colors.len() {
+            0 => Color::Reset,
+            1 => self.colors[0],
+            n => {
+                let t = life.clamp(0.0, 1.0) * (n - 1) as f32;
+                let idx = (t as usize).min(n - 2);
+                space.lerp(&self.colors[idx], &self.colors[idx + 1], t - idx as f32)
+            },
+        }
+    }
+}
+
+fn sample(rng: &mut SmallRng, range: &Range<f32>) -> f32 {
+    match range.is_empty() {
+        true  => range.start,
+        false => rng.gen_range(range.clone()),
+    }
+}
+
+impl ParticleSystemBuilder {
+    /// Adds an emitter to the system.
+    pub fn emitter<E: Into<Emitter>>(mut self, emitter: E) -> Self {
+        self.emitters.get_or_insert_with(Vec::new).push(emitter.into());
+        self
+    }
+
+    /// Seeds the random number generator, making the particles deterministic. Without a
+    /// seed or explicit `rng`, the default random number generator is used; see
+    /// [`rng`](crate::rng).
+    pub fn seed(self, seed: u64) -> Self {
+        self.rng(SmallRng::seed_from_u64(seed))
+    }
+}
+
+impl From<ParticleSystemBuilder> for Effect {
+    fn from(value: ParticleSystemBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl ParticleSystem {
+    pub fn builder() -> ParticleSystemBuilder { ParticleSystemBuilder::default() }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let dt = duration.as_secs_f32();
+
+        // move the living particles
+        let emitters = &self.emitters;
+        self.particles.iter_mut().for_each(|p| emitters[p.emitter].step(p, dt));
+
+        // spawn new particles; particles emitted at a rate are spread out over the
+        // frame, so that they don't clump together at low frame rates
+        for idx in 0..self.emitters.len() {
+            let (burst, spawned) = self.emitters[idx].spawn_count(duration);
+            for n in 0..burst + spawned {
+                let emitter = &self.emitters[idx];
+                let mut particle = emitter.spawn(idx, area, &mut self.rng);
+                if n >= burst {
+                    emitter.step(&mut particle, self.rng.gen_range(0.0..=dt));
+                }
+                self.particles.push(particle);
+            }
+        }
+        self.particles.retain(|p| p.age < p.lifetime);
+
+        // effects may be translated or resized beyond the edges of the buffer
+        let area = area.intersection(buf.area);
+        let selector = self.selection.selector(area);
+        let space = self.color_space.unwrap_or_else(global_color_space);
+        self.particles.iter().for_each(|p| {
+            let x = area.x as f32 + p.position.0;
+            let y = area.y as f32 + p.position.1 / CELL_ASPECT;
+            if x < 0.0 || y < 0.0 {
+                return;
+            }
+
+            let pos = Position::new(x as u16, y as u16);
+            if !area.contains(pos) {
+                return;
+            }
+
+            let cell = buf.get_mut(pos.x, pos.y);
+            if selector.is_valid(pos, cell) {
+                let emitter = &self.emitters[p.emitter];
+                let life = p.age / p.lifetime;
+                cell.set_char(emitter.glyph_at(life))
+                    .set_fg(emitter.color_at(life, space));
+            }
+        });
+
+        None
+    }
+
+    fn done(&self) -> bool {
+        self.particles.is_empty() && self.emitters.iter().all(Emitter::exhausted)
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+
+use crate::color_space::{global_color_space, ColorSpace};
+use crate::effect::Effect;
+use crate::effect_timer::EffectTimer;
+use crate::interpolation::Interpolation;
+use crate::pattern::Pattern;
+use crate::Shader;
+
+/// Plays the wrapped effect through a spatial pattern: each cell is blended from the
+/// start state of the effect to its end state, according to its alpha in the pattern.
+#[derive(Clone, Shader)]
+#[shader(process)]
+pub struct PatternedEffect {
+    pattern: Pattern,
+    #[shader(timer)]
+    timer: EffectTimer,
+    #[shader(effect)]
+    effect: Effect,
+}
+
+impl PatternedEffect {
+    pub fn new(pattern: Pattern, effect: Effect) -> Self {
+        let duration = effect.duration().unwrap_or_default();
+        let interpolation = effect.timer()
+            .map_or(Interpolation::Linear, EffectTimer::interpolation);
+
+        Self { pattern, timer: EffectTimer::new(duration, interpolation), effect }
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        let alpha = self.timer.alpha();
+        let area = self.effect.area().unwrap_or(area).intersection(buf.area);
+
+        let region = copy_region(buf, area);
+        let start = self.sample(Duration::ZERO, region.clone(), area);
+        let end = self.sample(self.timer.duration(), region, area);
+
+        let space = global_color_space();
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let (from, to) = (start.get(x, y), end.get(x, y));
+                if from != to {
+                    let a = self.pattern.cell_alpha(area, Position::new(x, y), alpha);
+                    *buf.get_mut(x, y) = blend(from, to, a, space);
+                }
+            }
+        }
+
+        overflow
+    }
+
+    /// Returns the region as processed by the wrapped effect at `elapsed`.
+    fn sample(&mut self, elapsed: Duration, mut region: Buffer, area: Rect) -> Buffer {
+        self.effect.seek(elapsed);
+        self.effect.process(Duration::ZERO, &mut region, area);
+        region
+    }
+}
+
+/// Copies the cells within `area` to a buffer of their own.
+fn copy_region(buf: &Buffer, area: Rect) -> Buffer {
+    let mut region = Buffer::empty(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            *region.get_mut(x, y) = buf.get(x, y).clone();
+        }
+    }
+
+    region
+}
+
+fn blend(from: &Cell, to: &Cell, alpha: f32, space: ColorSpace) -> Cell {
+    let mut cell = if alpha < 0.5 { from.clone() } else { to.clone() };
+    cell.set_fg(space.lerp(&from.fg, &to.fg, alpha));
+    cell.set_bg(space.lerp_bg(&from.bg, &to.bg, alpha));
+    cell
+}
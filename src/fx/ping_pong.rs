@@ -3,7 +3,7 @@ use ratatui::layout::Rect;
 
 use std::time::Duration;
 
-use crate::{CellFilter, CellIterator, Effect, EffectTimer, Shader};
+use crate::{CellFilter, CellIterator, ColorSpace, Effect, EffectTimer, Shader};
 
 #[derive(Clone)]
 pub struct PingPong {
@@ -69,6 +69,10 @@ impl Shader for PingPong {
         self.strategy = strategy;
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.fx.set_color_space(color_space);
+    }
+
     fn reverse(&mut self) {
         self.fx.reverse();
     }
@@ -80,4 +84,31 @@ impl Shader for PingPong {
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.strategy.clone())
     }
+
+    fn timer(&self) -> Option<&EffectTimer> {
+        self.fx.timer()
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        self.fx_original.duration().map(|d| d * 2)
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.fx = self.fx_original.clone();
+        match self.fx_original.duration() {
+            Some(d) if elapsed >= d => {
+                self.is_reversing = true;
+                self.fx.reverse();
+                self.fx.seek(elapsed - d);
+            },
+            _ => {
+                self.is_reversing = false;
+                self.fx.seek(elapsed);
+            },
+        }
+    }
+
+    fn loop_count(&self) -> u32 {
+        self.is_reversing as u32
+    }
 }
\ No newline at end of file
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::color_depth::{quantize, ColorDepth};
+use crate::Shader;
+
+#[derive(Clone, Shader)]
+#[shader(process, done)]
+pub struct QuantizeColors {
+    depth: ColorDepth,
+    dither: bool,
+    #[shader(area)]
+    area: Option<Rect>,
+}
+
+impl QuantizeColors {
+    pub fn new(depth: ColorDepth, dither: bool) -> Self {
+        Self { depth, dither, area: None }
+    }
+
+    fn process(
+        &mut self,
+        _duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        quantize(buf, area, self.depth, self.dither);
+        None
+    }
+
+    fn done(&self) -> bool { false }
+}
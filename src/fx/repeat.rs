@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use ratatui::buffer::Buffer;
 use ratatui::prelude::Rect;
-use crate::{CellIterator, EffectTimer};
+use crate::{CellIterator, ColorSpace, EffectTimer};
 
 use crate::effect::{Effect, CellFilter};
 use crate::shader::Shader;
@@ -11,68 +11,86 @@ use crate::shader::Shader;
 pub struct Repeat {
     fx: Effect,
     original: Effect,
-    mode: RepeatMode
+    mode: RepeatMode,
+    original_mode: RepeatMode,
+    loops: u32,
+    /// Time spent in the current iteration.
+    elapsed: Duration,
 }
 
 impl Repeat {
     pub fn new(fx: Effect, mode: RepeatMode) -> Self {
         let original = fx.clone();
-        Self { fx, original, mode }
-    }
-
-    fn process_effect(
-        &mut self,
-        duration: Duration,
-        buf: &mut Buffer,
-        area: Rect
-    ) -> Option<Duration> {
-        match self.fx.process(duration, buf, area) {
-            None => None,
-            Some(overflow) => {
-                self.fx = self.original.clone();
-                Some(overflow)
-            }
+        Self { fx, original, original_mode: mode.clone(), mode, loops: 0, elapsed: Duration::ZERO }
+    }
+
+    fn restart(&mut self) {
+        self.fx = self.original.clone();
+        self.loops += 1;
+        self.elapsed = Duration::ZERO;
+        if let RepeatMode::Times(n) = self.mode {
+            self.mode = RepeatMode::Times(n - 1);
+        }
+    }
+
+    /// Processes the current iteration. An iteration which finishes within `duration`
+    /// is followed by the next one, so unless it's the last iteration, it's rendered to
+    /// a scratch buffer rather than painting its end state under the next iteration.
+    fn process_iteration(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let may_finish = self.original.duration()
+            .map_or(true, |d| self.elapsed.saturating_add(duration) >= d);
+        let last_iteration = matches!(self.mode, RepeatMode::Times(0 | 1));
+        self.elapsed = self.elapsed.saturating_add(duration);
+
+        if !may_finish || last_iteration {
+            return self.fx.process(duration, buf, area);
+        }
+
+        let mut scratch = buf.clone();
+        let overflow = self.fx.process(duration, &mut scratch, area);
+        if overflow.is_none() {
+            *buf = scratch;
         }
+
+        overflow
     }
 }
 
 impl Shader for Repeat {
     fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
-        match self.mode {
-            RepeatMode::Forever => {
-                let overflow = self.fx.process(duration, buf, area);
-                if overflow.is_some() {
-                    self.fx = self.original.clone();
-                }
-                None
+        // time beyond the end of a RepeatMode::Duration is passed on as overflow
+        let (mut slice, excess) = match self.mode {
+            RepeatMode::Duration(d) => {
+                let slice = duration.min(d);
+                self.mode = RepeatMode::Duration(d - slice);
+                (slice, duration - slice)
+            },
+            _ => (duration, Duration::ZERO),
+        };
+
+        // the overflow of each iteration is carried over into the next one
+        let mut restarted = false;
+        while let Some(overflow) = self.process_iteration(slice, buf, area) {
+            if let RepeatMode::Times(0 | 1) = self.mode {
+                self.mode = RepeatMode::Times(0);
+                return Some(overflow);
             }
-            RepeatMode::Times(1) => {
-                let overflow = self.fx.process(duration, buf, area);
-                if overflow.is_some() {
-                    self.mode = RepeatMode::Times(0);
-                }
 
-                overflow
+            self.restart();
+            if restarted && overflow >= slice {
+                // the effect takes no time; resume with the next iteration next frame
+                return match self.mode {
+                    RepeatMode::Forever => None,
+                    _                   => Some(overflow + excess),
+                };
             }
-            RepeatMode::Times(n) => {
-                let overflow = self.fx.process(duration, buf, area);
-                if overflow.is_some() {
-                    self.mode = RepeatMode::Times(n - 1);
-                    self.fx = self.original.clone();
-                }
+            restarted = true;
+            slice = overflow;
+        }
 
-                overflow
-            }
-            RepeatMode::Duration(d) => {
-                if d < duration {
-                    let overflow = duration - d;
-                    self.mode = RepeatMode::Duration(Duration::ZERO);
-                    self.process_effect(d, buf, area).map(|d| Some(d + overflow)).unwrap_or(Some(overflow))
-                } else {
-                    self.mode = RepeatMode::Duration(d - duration);
-                    self.process_effect(duration, buf, area)
-                }
-            }
+        match self.mode {
+            RepeatMode::Duration(_) if !excess.is_zero() => Some(excess),
+            _                                            => None,
         }
     }
 
@@ -83,6 +101,7 @@ impl Shader for Repeat {
     fn done(&self) -> bool {
         match self.mode {
             RepeatMode::Times(0)                 => true,
+            RepeatMode::Times(1)                 => self.fx.done(),
             RepeatMode::Duration(Duration::ZERO) => true,
             _                                    => false,
         }
@@ -104,6 +123,10 @@ impl Shader for Repeat {
         self.fx.set_cell_selection(strategy);
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.fx.set_color_space(color_space);
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         None
     }
@@ -111,9 +134,75 @@ impl Shader for Repeat {
     fn cell_selection(&self) -> Option<CellFilter> {
         self.fx.cell_selection()
     }
+
+    fn duration(&self) -> Option<Duration> {
+        match self.original_mode {
+            RepeatMode::Forever     => None,
+            RepeatMode::Times(n)    => self.original.duration().map(|d| d * n),
+            RepeatMode::Duration(d) => Some(d),
+        }
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.fx = self.original.clone();
+        self.mode = self.original_mode.clone();
+        self.loops = 0;
+        self.elapsed = elapsed;
+
+        let fx_duration = match self.original.duration() {
+            Some(d) if !d.is_zero() => d,
+            _ => {
+                // unknown iteration length; seek within the first iteration
+                if let RepeatMode::Duration(d) = self.mode {
+                    self.mode = RepeatMode::Duration(d.saturating_sub(elapsed));
+                }
+                return self.fx.seek(elapsed);
+            }
+        };
+
+        // mirrors `process`: an iteration only restarts once its effect overflows, so
+        // an elapsed time on the boundary still belongs to the previous iteration
+        let time = match self.original_mode {
+            RepeatMode::Duration(d) => elapsed.min(d),
+            _                       => elapsed,
+        };
+        let iterations = match time.is_zero() {
+            true  => 0,
+            false => ((time.as_nanos() - 1) / fx_duration.as_nanos()).min(u32::MAX as u128) as u32,
+        };
+        let iteration_elapsed = time.saturating_sub(fx_duration * iterations);
+        self.elapsed = iteration_elapsed;
+
+        match self.original_mode {
+            RepeatMode::Forever => {
+                self.loops = iterations;
+                self.fx.seek(iteration_elapsed);
+            },
+            RepeatMode::Times(n) if iterations >= n => {
+                self.mode = RepeatMode::Times(0);
+                self.loops = n.saturating_sub(1);
+                self.fx.seek(fx_duration);
+            },
+            RepeatMode::Times(n) => {
+                self.mode = RepeatMode::Times(n - iterations);
+                self.loops = iterations;
+                self.fx.seek(iteration_elapsed);
+            },
+            RepeatMode::Duration(d) => {
+                self.mode = RepeatMode::Duration(d - time);
+                self.loops = iterations;
+                self.fx.seek(iteration_elapsed);
+            },
+        }
+    }
+
+    fn loop_count(&self) -> u32 {
+        self.loops
+    }
 }
 
-#[derive(Clone)]
+/// Specifies how many times, or for how long, a [Repeat]ed effect is played.
+#[derive(Clone, Debug)]
 pub enum RepeatMode {
     Forever,
     Times(u32),
@@ -3,12 +3,11 @@ use ratatui::buffer::Buffer;
 use ratatui::prelude::Rect;
 use ratatui::widgets::Clear;
 use ratatui::widgets::Widget;
-use crate::CellIterator;
+use crate::{CellIterator, ColorSpace};
 use crate::effect::{Effect, CellFilter};
 use crate::effect_timer::EffectTimer;
 use crate::interpolation::Interpolatable;
 use crate::rect_ext::CenteredShrink;
-use crate::render_effect::EffectRenderer;
 use crate::shader::Shader;
 
 #[derive(Clone)]
@@ -50,7 +49,7 @@ impl Shader for ResizeArea {
         self.set_area(resized_area);
         
         if let Some(fx) = self.fx.as_mut() {
-            buf.render_effect(fx, resized_area, duration);
+            fx.process(duration, buf, resized_area);
         }
         
         remaining
@@ -87,6 +86,12 @@ impl Shader for ResizeArea {
         }
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_color_space(color_space);
+        }
+    }
+
     fn reverse(&mut self) {
         self.lifetime = self.lifetime.reversed();
     }
@@ -95,7 +100,26 @@ impl Shader for ResizeArea {
         Some(&mut self.lifetime)
     }
 
+    fn timer(&self) -> Option<&EffectTimer> {
+        Some(&self.lifetime)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         self.fx.as_ref().and_then(Effect::cell_selection)
     }
+
+    fn duration(&self) -> Option<Duration> {
+        let duration = self.lifetime.duration();
+        match self.fx.as_ref() {
+            Some(fx) => fx.duration().map(|d| d.max(duration)),
+            None     => Some(duration),
+        }
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.lifetime.set_elapsed(elapsed);
+        if let Some(fx) = self.fx.as_mut() {
+            fx.seek(elapsed);
+        }
+    }
 }
\ No newline at end of file
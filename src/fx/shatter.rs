@@ -0,0 +1,128 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use rand::Rng;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::gradient::{scaled, scaled_size, CELL_ASPECT};
+use crate::noise::hash;
+use crate::rng::default_rng;
+use crate::Shader;
+
+/// Shatters the content of the area: every cell with a glyph flies outward from a center
+/// point, or falls with gravity, carrying its symbol and style with it and clearing its
+/// original location. Reversed, the scattered glyphs fly back into place, assembling the
+/// content. Speeds and accelerations are measured in columns, with rows counting as two
+/// columns; glyphs may fly beyond the area, but not beyond the buffer.
+///
+/// The trajectory of each cell is fixed by the seed, so the effect can be seeked and
+/// reversed freely.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{Effect, Interpolation};
+/// use tachyonfx::fx::Shatter;
+///
+/// // the content crumbles and falls out of view
+/// let crumble: Effect = Shatter::builder()
+///     .speed(0.0..4.0)
+///     .gravity(60.0)
+///     .seed(7)
+///     .lifetime((800, Interpolation::Linear).into())
+///     .into();
+/// ```
+#[derive(Builder, Clone, Shader)]
+#[builder(pattern = "owned")]
+#[shader(process)]
+pub struct Shatter {
+    /// The point the glyphs fly away from, relative to the area.
+    #[builder(default = "(0.5, 0.5)")]
+    center: (f32, f32),
+    /// The range of launch speeds, in columns per second.
+    #[builder(default = "10.0..40.0")]
+    speed: Range<f32>,
+    /// The maximum deviation, in degrees, from flying straight away from the center.
+    #[builder(default = "30.0")]
+    spread: f32,
+    /// The downward acceleration, in columns per second squared.
+    #[builder(default)]
+    gravity: f32,
+    #[builder(default = "default_rng().gen()")]
+    seed: u64,
+    #[shader(timer)]
+    lifetime: EffectTimer,
+    #[builder(default)]
+    #[shader(area)]
+    area: Option<Rect>,
+    #[builder(default)]
+    #[shader(filter)]
+    cell_filter: CellFilter,
+}
+
+impl From<ShatterBuilder> for Effect {
+    fn from(value: ShatterBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl Shatter {
+    pub fn builder() -> ShatterBuilder { ShatterBuilder::default() }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        let t = self.lifetime.duration().as_secs_f32() * self.lifetime.alpha();
+
+        let cells: Vec<(Position, Cell)> = self.cell_iter(buf, area)
+            .filter(|(_, cell)| !cell.symbol().trim().is_empty())
+            .map(|(pos, cell)| {
+                let moved = cell.clone();
+                cell.reset();
+                (pos, moved)
+            })
+            .collect();
+
+        for (pos, cell) in cells {
+            let (x, y) = self.displacement(area, pos, t);
+            let x = (pos.x as f32 + x).round();
+            let y = (pos.y as f32 + y / CELL_ASPECT).round();
+            if x < 0.0 || y < 0.0 {
+                continue;
+            }
+
+            let target = Position::new(x as u16, y as u16);
+            if buf.area.contains(target) {
+                *buf.get_mut(target.x, target.y) = cell;
+            }
+        }
+
+        overflow
+    }
+
+    /// Returns how far the cell at `pos` has flown after `t` seconds.
+    fn displacement(&self, area: Rect, pos: Position, t: f32) -> (f32, f32) {
+        let (x, y) = scaled(area, pos);
+        let (w, h) = scaled_size(area);
+        let (dx, dy) = (x - self.center.0 * w, y - self.center.1 * h);
+
+        let (rx, ry) = ((pos.x - area.x) as i32, (pos.y - area.y) as i32);
+        let jitter = (2.0 * hash(rx, ry, self.seed) - 1.0) * self.spread;
+        let angle = match dx == 0.0 && dy == 0.0 {
+            true  => hash(rx, ry, self.seed ^ 1) * 360.0,
+            false => dy.atan2(dx).to_degrees() + jitter,
+        };
+        let speed = self.speed.start
+            + (self.speed.end - self.speed.start) * hash(rx, ry, self.seed ^ 2);
+
+        let (sin, cos) = angle.to_radians().sin_cos();
+        (cos * speed * t, sin * speed * t + 0.5 * self.gravity * t * t)
+    }
+}
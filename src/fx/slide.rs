@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+
+use crate::effect_timer::EffectTimer;
+use crate::interpolation::Interpolatable;
+use crate::Shader;
+
+/// Moves the rendered content of the area by an animated offset, from `from` to `to`, in
+/// cells. Content moved beyond the area is drawn over the surrounding cells, clipped at
+/// the edges of the buffer, and the cells it vacates are cleared to the `fill` style.
+///
+/// The offset is not clamped to the start and end, so interpolations such as `BackOut`
+/// overshoot the final position before settling.
+#[derive(Clone, Shader)]
+#[shader(process)]
+pub struct Slide {
+    from: (i16, i16),
+    to: (i16, i16),
+    fill: Style,
+    #[shader(timer)]
+    lifetime: EffectTimer,
+    #[shader(area)]
+    area: Option<Rect>,
+}
+
+impl Slide {
+    pub fn new(
+        from: (i16, i16),
+        to: (i16, i16),
+        fill: Style,
+        lifetime: EffectTimer,
+    ) -> Self {
+        Self { from, to, fill, lifetime, area: None }
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        let alpha = self.lifetime.alpha();
+
+        let dx = (self.from.0 as f32).lerp(&(self.to.0 as f32), alpha).round() as i32;
+        let dy = (self.from.1 as f32).lerp(&(self.to.1 as f32), alpha).round() as i32;
+
+        // effects may be translated or resized beyond the edges of the buffer
+        let area = area.intersection(buf.area);
+        if (dx, dy) == (0, 0) || area.is_empty() {
+            return overflow;
+        }
+
+        let mut content: Vec<Cell> = Vec::with_capacity(area.area() as usize);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = buf.get_mut(x, y);
+                content.push(cell.clone());
+                cell.reset();
+                cell.set_style(self.fill);
+            }
+        }
+
+        let bounds = buf.area;
+        let positions = (area.top()..area.bottom())
+            .flat_map(|y| (area.left()..area.right()).map(move |x| (x, y)));
+
+        positions.zip(content).for_each(|((x, y), cell)| {
+            let (x, y) = (x as i32 + dx, y as i32 + dy);
+            let inside = x >= bounds.left() as i32 && x < bounds.right() as i32
+                && y >= bounds.top() as i32 && y < bounds.bottom() as i32;
+
+            if inside {
+                *buf.get_mut(x as u16, y as u16) = cell;
+            }
+        });
+
+        overflow
+    }
+}
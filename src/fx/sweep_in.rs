@@ -5,23 +5,38 @@ use ratatui::prelude::Color;
 
 use Interpolation::CircOut;
 
-use crate::{CellIterator, ColorMapper};
+use crate::{CellIterator, ColorMapper, ColorSpace, Gradient, GradientGeometry};
+use crate::color_space::global_color_space;
 use crate::effect::CellFilter;
 use crate::effect_timer::EffectTimer;
-use crate::interpolation::{Interpolatable, Interpolation};
-use crate::shader::Shader;
+use crate::interpolation::Interpolation;
+use crate::Shader;
 
-#[derive(Clone)]
+#[derive(Clone, Shader)]
 pub struct SweepIn {
     gradient_length: u16,
     faded_color: Color,
+    /// Takes precedence over `faded_color`; each cell is swept in from its color in the gradient.
+    faded_gradient: Option<Gradient>,
+    #[shader(timer)]
     lifetime: EffectTimer,
+    #[shader(area)]
     area: Option<Rect>,
+    #[shader(filter)]
     cell_filter: CellFilter,
-    direction: Direction,
+    #[shader(color_space)]
+    color_space: Option<ColorSpace>,
+    sweep: Sweep,
 }
 
 #[derive(Clone, Copy)]
+enum Sweep {
+    Direction(Direction),
+    Shape(SweepShape),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     LeftToRight,
     RightToLeft,
@@ -29,6 +44,68 @@ pub enum Direction {
     DownToUp,
 }
 
+impl Direction {
+    /// Returns the opposite direction.
+    pub(crate) fn flipped(self) -> Self {
+        match self {
+            Direction::LeftToRight => Direction::RightToLeft,
+            Direction::RightToLeft => Direction::LeftToRight,
+            Direction::UpToDown    => Direction::DownToUp,
+            Direction::DownToUp    => Direction::UpToDown,
+        }
+    }
+}
+
+/// The shape of a sweep, beyond the four [Direction]s. Centers are given relative to the
+/// area, with `(0.5, 0.5)` as the middle, and angles in degrees, clockwise from
+/// left-to-right. The `gradient_length` of the sweep is measured in columns, with rows
+/// counting as two columns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SweepShape {
+    /// Sweeps along a line at `angle` degrees; `0.0` sweeps from left to right, and
+    /// `90.0` from top to bottom.
+    Linear { angle: f32 },
+    /// Sweeps outwards from `center`, like an iris opening.
+    IrisOpen { center: (f32, f32) },
+    /// Sweeps inwards towards `center`, like an iris closing.
+    IrisClose { center: (f32, f32) },
+    /// Sweeps clockwise around `center`, starting at `start_angle` degrees, like the
+    /// hand of a clock.
+    Clockwise { center: (f32, f32), start_angle: f32 },
+    /// Sweeps counter-clockwise around `center`, starting at `start_angle` degrees.
+    CounterClockwise { center: (f32, f32), start_angle: f32 },
+}
+
+impl SweepShape {
+    /// Returns the shape sweeping in the opposite direction.
+    pub(crate) fn flipped(self) -> Self {
+        match self {
+            SweepShape::Linear { angle }     => SweepShape::Linear { angle: angle + 180.0 },
+            SweepShape::IrisOpen { center }  => SweepShape::IrisClose { center },
+            SweepShape::IrisClose { center } => SweepShape::IrisOpen { center },
+            SweepShape::Clockwise { center, start_angle } =>
+                SweepShape::CounterClockwise { center, start_angle },
+            SweepShape::CounterClockwise { center, start_angle } =>
+                SweepShape::Clockwise { center, start_angle },
+        }
+    }
+
+    /// Returns the geometry the sweep progresses along, and whether it progresses
+    /// from the end of the geometry towards its start.
+    fn geometry(self) -> (GradientGeometry, bool) {
+        match self {
+            SweepShape::Linear { angle }     => (GradientGeometry::Linear { angle }, false),
+            SweepShape::IrisOpen { center }  => (GradientGeometry::Radial { center }, false),
+            SweepShape::IrisClose { center } => (GradientGeometry::Radial { center }, true),
+            SweepShape::Clockwise { center, start_angle } =>
+                (GradientGeometry::Angular { center, start_angle }, false),
+            SweepShape::CounterClockwise { center, start_angle } =>
+                (GradientGeometry::Angular { center, start_angle }, true),
+        }
+    }
+}
+
 impl SweepIn {
     pub fn new(
         direction: Direction,
@@ -44,15 +121,34 @@ impl SweepIn {
         };
 
         Self {
-            direction,
+            sweep: Sweep::Direction(direction),
             gradient_length,
             faded_color,
+            faded_gradient: None,
             lifetime: timer,
             area: None,
             cell_filter: CellFilter::All,
+            color_space: None,
         }
     }
 
+    pub fn with_shape(
+        shape: SweepShape,
+        gradient_length: u16,
+        faded_color: Color,
+        lifetime: EffectTimer,
+    ) -> Self {
+        Self {
+            sweep: Sweep::Shape(shape),
+            ..Self::new(Direction::LeftToRight, gradient_length, faded_color, lifetime)
+        }
+    }
+
+    pub fn with_faded_gradient(mut self, gradient: Gradient) -> Self {
+        self.faded_gradient = Some(gradient);
+        self
+    }
+
     fn horizontal_gradient(&self, area: Rect, alpha: f32) -> Range<f32> {
         let gradient_len = self.gradient_length as f32;
         let x_start = (area.x as f32 - gradient_len) + ((area.width as f32 + gradient_len) * alpha);
@@ -69,37 +165,49 @@ impl SweepIn {
         y_start..y_end
     }
 
-}
-
-impl Shader for SweepIn {
     fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator) {
-        let direction = self.direction;
-        let gradient = match direction {
-            Direction::LeftToRight | Direction::RightToLeft =>
-                self.horizontal_gradient(area, alpha),
-            Direction::UpToDown | Direction::DownToUp =>
-                self.vertical_gradient(area, alpha),
+        let window_alpha = match self.sweep {
+            Sweep::Direction(direction) => {
+                let gradient = match direction {
+                    Direction::LeftToRight | Direction::RightToLeft =>
+                        self.horizontal_gradient(area, alpha),
+                    Direction::UpToDown | Direction::DownToUp =>
+                        self.vertical_gradient(area, alpha),
+                };
+                window_alpha_fn(direction, gradient)
+            },
+            Sweep::Shape(shape) => shape_alpha_fn(shape, area, self.gradient_length as f32, alpha),
         };
 
-        let window_alpha = window_alpha_fn(direction, gradient);
-
         let mut fg_mapper = ColorMapper::default();
         let mut bg_mapper = ColorMapper::default();
+        let space = self.color_space.unwrap_or_else(global_color_space);
 
         cell_iter.for_each(|(pos, cell)| {
             let a = window_alpha(pos);
+            let faded_color = match self.faded_gradient.as_ref() {
+                Some(gradient) if a < 1.0 => gradient.color_at_with(
+                    gradient.position(area, pos),
+                    |from, to, alpha| space.lerp(from, to, alpha),
+                ),
+                _ => self.faded_color,
+            };
 
             match a {
                 0.0 => {
-                    cell.set_fg(self.faded_color);
-                    cell.set_bg(self.faded_color);
+                    cell.set_fg(faded_color);
+                    cell.set_bg(faded_color);
                 },
                 1.0 => {} // nothing to do
+                _ if self.faded_gradient.is_some() => {
+                    cell.set_fg(space.lerp(&faded_color, &cell.fg, CircOut.alpha(a)));
+                    cell.set_bg(space.lerp_bg(&faded_color, &cell.bg, CircOut.alpha(a)));
+                },
                 _ => {
                     let fg = fg_mapper
-                        .map(cell.fg, a, |c| self.faded_color.tween(&c, a, CircOut));
+                        .map(cell.fg, a, |c| space.lerp(&faded_color, &c, CircOut.alpha(a)));
                     let bg = bg_mapper
-                        .map(cell.bg, a, |c| self.faded_color.tween(&c, a, CircOut));
+                        .map(cell.bg, a, |c| space.lerp_bg(&faded_color, &c, CircOut.alpha(a)));
 
                     cell.set_fg(fg);
                     cell.set_bg(bg);
@@ -107,38 +215,6 @@ impl Shader for SweepIn {
             }
         });
     }
-
-    fn done(&self) -> bool {
-        self.lifetime.done()
-    }
-
-    fn clone_box(&self) -> Box<dyn Shader> {
-        Box::new(self.clone())
-    }
-
-    fn area(&self) -> Option<Rect> {
-        self.area
-    }
-
-    fn set_area(&mut self, area: Rect) {
-        self.area = Some(area)
-    }
-
-    fn set_cell_selection(&mut self, strategy: CellFilter) {
-        self.cell_filter = strategy;
-    }
-
-    fn reverse(&mut self) {
-        self.lifetime = self.lifetime.reversed();
-    }
-
-    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
-        Some(&mut self.lifetime)
-    }
-
-    fn cell_selection(&self) -> Option<CellFilter> {
-        Some(self.cell_filter.clone())
-    }
 }
 
 fn window_alpha_fn(
@@ -176,4 +252,26 @@ fn window_alpha_fn(
             }
         }),
     }
-}
\ No newline at end of file
+}
+
+fn shape_alpha_fn(
+    shape: SweepShape,
+    area: Rect,
+    gradient_len: f32,
+    alpha: f32,
+) -> Box<dyn Fn(Position) -> f32> {
+    let (geometry, inverted) = shape.geometry();
+    let extent = geometry.extent(area);
+    let gradient_start = -gradient_len + (extent + gradient_len) * alpha;
+
+    Box::new(move |p: Position| -> f32 {
+        let t = geometry.position(area, p);
+        let distance = extent * if inverted { 1.0 - t } else { t };
+
+        match distance - gradient_start {
+            d if d < 0.0          => 1.0,
+            d if d < gradient_len => 1.0 - d / gradient_len,
+            _                     => 0.0,
+        }
+    })
+}
@@ -1,7 +1,7 @@
 use std::time::Duration;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use crate::CellIterator;
+use crate::{CellIterator, ColorSpace};
 use crate::effect::{Effect, CellFilter, IntoEffect};
 use crate::effect_timer::EffectTimer;
 use crate::interpolation::Interpolation::Linear;
@@ -56,6 +56,10 @@ impl Shader for TemporaryEffect {
         self.effect.set_cell_selection(strategy);
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.effect.set_color_space(color_space);
+    }
+
     fn reverse(&mut self) {
         self.effect.reverse()
     }
@@ -67,6 +71,24 @@ impl Shader for TemporaryEffect {
     fn cell_selection(&self) -> Option<CellFilter> {
         self.effect.cell_selection()
     }
+
+    fn timer(&self) -> Option<&EffectTimer> {
+        Some(&self.duration)
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        let duration = self.duration.duration();
+        Some(self.effect.duration().map_or(duration, |d| d.min(duration)))
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.duration.set_elapsed(elapsed);
+        self.effect.seek(elapsed);
+    }
+
+    fn loop_count(&self) -> u32 {
+        self.effect.loop_count()
+    }
 }
 
 pub trait IntoTemporaryEffect {
@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use rand::prelude::{SeedableRng, SmallRng};
+use rand::Rng;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+
+use crate::color_space::{global_color_space, ColorSpace};
+use crate::effect::CellFilter;
+use crate::effect_timer::EffectTimer;
+use crate::fx::Direction;
+use crate::palette::resolve;
+use crate::rng::default_rng;
+use crate::Shader;
+
+/// Transitions from a captured buffer, the previous screen, to the buffer being
+/// processed, the freshly rendered screen.
+#[derive(Clone, Shader)]
+#[shader(process)]
+pub struct Transition {
+    from: Buffer,
+    style: TransitionStyle,
+    #[shader(timer)]
+    lifetime: EffectTimer,
+    #[shader(area)]
+    area: Option<Rect>,
+    #[shader(filter)]
+    cell_filter: CellFilter,
+    #[shader(color_space)]
+    color_space: Option<ColorSpace>,
+}
+
+#[derive(Clone)]
+enum TransitionStyle {
+    CrossFade,
+    Dissolve { cyclic_cell_activation: Vec<f32> },
+    Slide(Direction),
+}
+
+impl Transition {
+    pub fn cross_fade(from: Buffer, lifetime: EffectTimer) -> Self {
+        Self::new(from, TransitionStyle::CrossFade, lifetime)
+    }
+
+    /// Creates a dissolve transition using the default random number generator;
+    /// see [`rng`](crate::rng).
+    pub fn dissolve(from: Buffer, cell_cycle: usize, lifetime: EffectTimer) -> Self {
+        Self::dissolve_with_rng(from, cell_cycle, lifetime, default_rng())
+    }
+
+    /// Creates a dissolve transition with a deterministic cell activation order.
+    pub fn dissolve_seeded(from: Buffer, cell_cycle: usize, seed: u64, lifetime: EffectTimer) -> Self {
+        Self::dissolve_with_rng(from, cell_cycle, lifetime, SmallRng::seed_from_u64(seed))
+    }
+
+    fn dissolve_with_rng(
+        from: Buffer,
+        cell_cycle: usize,
+        lifetime: EffectTimer,
+        mut rng: SmallRng,
+    ) -> Self {
+        let cyclic_cell_activation = (0..cell_cycle.max(1))
+            .map(|_| rng.gen_range(0.0..1.0))
+            .collect();
+
+        Self::new(from, TransitionStyle::Dissolve { cyclic_cell_activation }, lifetime)
+    }
+
+    pub fn slide(from: Buffer, direction: Direction, lifetime: EffectTimer) -> Self {
+        Self::new(from, TransitionStyle::Slide(direction), lifetime)
+    }
+
+    fn new(from: Buffer, style: TransitionStyle, lifetime: EffectTimer) -> Self {
+        Self { from, style, lifetime, area: None, cell_filter: CellFilter::All, color_space: None }
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let overflow = self.lifetime.process(duration);
+        let alpha = self.lifetime.alpha();
+
+        // the new screen, as rendered before any cells are replaced
+        let to = match self.style {
+            TransitionStyle::Slide(_) => Some(buf.clone()),
+            _                         => None,
+        };
+
+        let space = self.color_space.unwrap_or_else(global_color_space);
+        let cells = self.cell_iter(buf, area);
+        match &self.style {
+            TransitionStyle::CrossFade => cells.for_each(|(pos, cell)| {
+                if let Some(from) = old_cell(&self.from, pos) {
+                    cross_fade_cell(from, cell, alpha, space);
+                }
+            }),
+            TransitionStyle::Dissolve { cyclic_cell_activation } => cells.enumerate()
+                .filter(|(idx, _)| alpha <= cyclic_cell_activation[idx % cyclic_cell_activation.len()])
+                .for_each(|(_, (pos, cell))| {
+                    if let Some(from) = old_cell(&self.from, pos) {
+                        *cell = from.clone();
+                    }
+                }),
+            TransitionStyle::Slide(direction) => {
+                let to = to.unwrap();
+                cells.for_each(|(pos, cell)| {
+                    let source = match slide_source(*direction, area, pos, alpha) {
+                        SlideSource::From(pos) => old_cell(&self.from, pos),
+                        SlideSource::To(pos)   => old_cell(&to, pos),
+                        SlideSource::Vacant    => {
+                            cell.reset();
+                            None
+                        },
+                    };
+                    if let Some(source) = source {
+                        *cell = source.clone();
+                    }
+                });
+            },
+        }
+
+        overflow
+    }
+}
+
+/// Returns the cell at `pos` of the captured buffer, if it covers the position.
+fn old_cell(buf: &Buffer, pos: Position) -> Option<&Cell> {
+    buf.area.contains(pos).then(|| buf.get(pos.x, pos.y))
+}
+
+/// Fades out the old cell's text over the first half of the transition, and fades in the
+/// new cell's text over the second half, while the background gradually shifts from
+/// the old to the new color.
+fn cross_fade_cell(from: &Cell, to: &mut Cell, alpha: f32, space: ColorSpace) {
+    let bg = space.lerp_bg(&from.bg, &to.bg, alpha);
+    let resolved_bg = resolve(bg, true);
+    if alpha < 0.5 {
+        let fg = space.lerp(&from.fg, &resolved_bg, alpha * 2.0);
+        *to = from.clone();
+        to.set_fg(fg);
+    } else {
+        let fg = space.lerp(&resolved_bg, &to.fg, (alpha - 0.5) * 2.0);
+        to.set_fg(fg);
+    }
+    to.set_bg(bg);
+}
+
+enum SlideSource {
+    From(Position),
+    To(Position),
+    /// Neither screen covers the position, as when an overshooting interpolation pushes
+    /// the new screen past its final position.
+    Vacant,
+}
+
+/// Resolves which screen, and which of its cells, is visible at `pos` while both screens
+/// are pushed in `direction` by `alpha` of the area's extent. `alpha` may fall outside of
+/// `0.0..=1.0` for interpolations which undershoot or overshoot.
+fn slide_source(direction: Direction, area: Rect, pos: Position, alpha: f32) -> SlideSource {
+    let (x, y) = ((pos.x - area.x) as i32, (pos.y - area.y) as i32);
+    let (w, h) = (area.width as i32, area.height as i32);
+    let offset_x = (w as f32 * alpha).round() as i32;
+    let offset_y = (h as f32 * alpha).round() as i32;
+
+    // the cells of the old and the new screen at `pos`, relative to the area
+    let (from, to) = match direction {
+        Direction::LeftToRight => ((x - offset_x, y), (x - offset_x + w, y)),
+        Direction::RightToLeft => ((x + offset_x, y), (x + offset_x - w, y)),
+        Direction::UpToDown    => ((x, y - offset_y), (x, y - offset_y + h)),
+        Direction::DownToUp    => ((x, y + offset_y), (x, y + offset_y - h)),
+    };
+
+    let covers = |(x, y): (i32, i32)| (0..w).contains(&x) && (0..h).contains(&y);
+    let at = |(x, y): (i32, i32)| Position::new(area.x + x as u16, area.y + y as u16);
+    match (covers(from), covers(to)) {
+        (true, _)  => SlideSource::From(at(from)),
+        (_, true)  => SlideSource::To(at(to)),
+        _          => SlideSource::Vacant,
+    }
+}
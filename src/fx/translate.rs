@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use ratatui::buffer::Buffer;
 use ratatui::prelude::Rect;
-use crate::CellIterator;
+use crate::{CellIterator, ColorSpace};
 
 use crate::effect::{Effect, CellFilter};
 use crate::effect_timer::EffectTimer;
@@ -64,6 +64,7 @@ impl Shader for Translate {
 
     fn done(&self) -> bool {
         self.lifetime.done()
+            && (self.fx.as_ref().is_some_and(|fx| fx.done()) || self.fx.is_none())
     }
 
     fn clone_box(&self) -> Box<dyn Shader> {
@@ -92,15 +93,40 @@ impl Shader for Translate {
         }
     }
 
+    fn set_color_space(&mut self, color_space: ColorSpace) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_color_space(color_space);
+        }
+    }
+
     fn reverse(&mut self) {
         self.lifetime = self.lifetime.reversed()
     }
 
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
-        todo!()
+        Some(&mut self.lifetime)
     }
 
     fn cell_selection(&self) -> Option<CellFilter> {
-        todo!()
+        self.fx.as_ref().and_then(Effect::cell_selection)
+    }
+
+    fn timer(&self) -> Option<&EffectTimer> {
+        Some(&self.lifetime)
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        let duration = self.lifetime.duration();
+        match self.fx.as_ref() {
+            Some(fx) => fx.duration().map(|d| d.max(duration)),
+            None     => Some(duration),
+        }
+    }
+
+    fn seek(&mut self, elapsed: Duration) {
+        self.lifetime.set_elapsed(elapsed);
+        if let Some(fx) = self.fx.as_mut() {
+            fx.seek(elapsed);
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use rand::Rng;
+use ratatui::buffer::Cell;
+use ratatui::layout::{Position, Rect};
+
+use crate::effect::{CellFilter, Effect, IntoEffect};
+use crate::effect_timer::EffectTimer;
+use crate::noise::hash;
+use crate::rng::default_rng;
+use crate::{CellIterator, Shader};
+
+/// Reveals the cells one character at a time in reading order, left to right and top to
+/// bottom, like a typewriter or a streamed chat message. Whitespace takes no time to type,
+/// so runs of whitespace are skipped over. Cells not yet typed are blanked out, and the
+/// next cell to be typed can show a blinking caret.
+///
+/// Reversing the effect, e.g. with [reversed](crate::Effect::reversed), backspaces the
+/// text instead.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use tachyonfx::{CellFilter, Effect};
+/// use tachyonfx::fx::Typewriter;
+///
+/// let typing: Effect = Typewriter::builder()
+///     .caret('▌')
+///     .caret_blink(Duration::from_millis(300))
+///     .jitter(0.4)
+///     .seed(7)
+///     .lifetime(1200.into())
+///     .cell_filter(CellFilter::Text)
+///     .into();
+///
+/// let backspacing = typing.reversed();
+/// ```
+#[derive(Builder, Clone, Shader)]
+#[builder(pattern = "owned")]
+pub struct Typewriter {
+    /// Drawn over the next cell to be typed.
+    #[builder(default, setter(strip_option))]
+    caret: Option<char>,
+    /// How long the caret stays visible, and hidden, when blinking; `Duration::ZERO`
+    /// keeps the caret solid.
+    #[builder(default = "Duration::from_millis(400)")]
+    caret_blink: Duration,
+    /// How much the time taken to type each character varies, from `0.0` (a steady
+    /// rhythm) to `1.0`.
+    #[builder(default)]
+    jitter: f32,
+    #[builder(default = "default_rng().gen()")]
+    seed: u64,
+    #[shader(timer)]
+    lifetime: EffectTimer,
+    #[builder(default)]
+    #[shader(area)]
+    area: Option<Rect>,
+    #[builder(default)]
+    #[shader(filter)]
+    cell_filter: CellFilter,
+}
+
+impl From<TypewriterBuilder> for Effect {
+    fn from(value: TypewriterBuilder) -> Self {
+        value.build().unwrap().into_effect()
+    }
+}
+
+impl Typewriter {
+    pub fn builder() -> TypewriterBuilder { TypewriterBuilder::default() }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let mut cells: Vec<(Position, &mut Cell)> = cell_iter.collect();
+        let keystrokes: Vec<f32> = cells.iter()
+            .map(|(pos, cell)| self.keystroke_duration(*pos, cell))
+            .collect();
+
+        let typed_until = alpha.clamp(0.0, 1.0) * keystrokes.iter().sum::<f32>();
+        let mut elapsed = 0.0;
+        let typed = keystrokes.iter()
+            .take_while(|d| { elapsed += *d; elapsed <= typed_until })
+            .count();
+
+        let untyped = &mut cells[typed..];
+        untyped.iter_mut().for_each(|(_, cell)| { cell.set_char(' '); });
+
+        if let (Some(caret), Some((_, cell))) = (self.caret, untyped.first_mut()) {
+            if self.caret_visible() {
+                cell.set_char(caret);
+            }
+        }
+    }
+
+    /// Returns the relative time it takes to type the cell.
+    fn keystroke_duration(&self, pos: Position, cell: &Cell) -> f32 {
+        if cell.symbol().trim().is_empty() {
+            return 0.0;
+        }
+
+        let jitter = self.jitter.clamp(0.0, 1.0) * 0.9;
+        1.0 + jitter * (2.0 * hash(pos.x as i32, pos.y as i32, self.seed) - 1.0)
+    }
+
+    fn caret_visible(&self) -> bool {
+        match self.caret_blink.as_millis() {
+            0     => true,
+            blink => self.lifetime.elapsed().as_millis() % (2 * blink) < blink,
+        }
+    }
+}
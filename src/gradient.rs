@@ -0,0 +1,203 @@
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::color_space::global_color_space;
+
+/// Terminal cells are roughly twice as tall as they are wide; vertical distances are
+/// scaled by this factor so that radial gradients come out round.
+pub(crate) const CELL_ASPECT: f32 = 2.0;
+
+/// A multi-stop color gradient laid out over an area, e.g. for title bars, rainbow
+/// highlights or sunset-style backgrounds. Gradients are painted by effects such as
+/// [fade_to_gradient](crate::fx::fade_to_gradient) and
+/// [sweep_in_gradient](crate::fx::sweep_in_gradient), which interpolate between the stops
+/// in the effect's [color space](crate::ColorSpace).
+///
+/// # Example
+/// ```
+/// use ratatui::layout::{Position, Rect};
+/// use ratatui::style::Color;
+/// use tachyonfx::Gradient;
+///
+/// let sunset = Gradient::linear(0.0)
+///     .stop(0.0, Color::Rgb(255, 0, 0))
+///     .stop(1.0, Color::Rgb(0, 0, 255));
+///
+/// let area = Rect::new(0, 0, 10, 1);
+/// assert_eq!(sunset.position(area, Position::new(0, 0)), 0.05);
+/// assert_eq!(sunset.color_at(0.0), Color::Rgb(255, 0, 0));
+/// assert_eq!(sunset.color_at(0.5), Color::Rgb(255, 0, 255));
+/// assert_eq!(sunset.color_in(area, Position::new(9, 0)), Color::Rgb(25, 0, 255));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    geometry: GradientGeometry,
+    stops: Vec<(f32, Color)>,
+}
+
+/// How a [Gradient] is laid out over an area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GradientGeometry {
+    /// Along a line at `angle` degrees, clockwise from left-to-right; `90.0` runs from top
+    /// to bottom. The gradient spans the full extent of the area along the line.
+    Linear { angle: f32 },
+    /// Outwards from `center`, relative to the area, reaching the last stop at the
+    /// farthest corner.
+    Radial { center: (f32, f32) },
+    /// Clockwise around `center`, relative to the area, starting at `start_angle` degrees.
+    Angular { center: (f32, f32), start_angle: f32 },
+}
+
+impl Gradient {
+    /// Creates a gradient without stops with the specified geometry.
+    pub fn new(geometry: GradientGeometry) -> Self {
+        Self { geometry, stops: Vec::new() }
+    }
+
+    /// Creates a linear gradient along a line at `angle` degrees, clockwise from
+    /// left-to-right.
+    pub fn linear(angle: f32) -> Self {
+        Self::new(GradientGeometry::Linear { angle })
+    }
+
+    /// Creates a radial gradient around `center`, given relative to the area; `(0.5, 0.5)`
+    /// is the middle of the area.
+    pub fn radial(center: (f32, f32)) -> Self {
+        Self::new(GradientGeometry::Radial { center })
+    }
+
+    /// Creates an angular (conic) gradient sweeping clockwise around `center`, given
+    /// relative to the area, starting at `start_angle` degrees.
+    pub fn angular(center: (f32, f32), start_angle: f32) -> Self {
+        Self::new(GradientGeometry::Angular { center, start_angle })
+    }
+
+    /// Adds a color stop. Stops may be added in any order.
+    ///
+    /// # Arguments
+    /// * `at` - The position of the stop, from `0.0` to `1.0`.
+    /// * `color` - The color at the stop.
+    ///
+    /// # Returns
+    /// * The gradient with the added stop.
+    pub fn stop<C: Into<Color>>(mut self, at: f32, color: C) -> Self {
+        let at = at.clamp(0.0, 1.0);
+        let idx = self.stops.partition_point(|(stop, _)| *stop <= at);
+        self.stops.insert(idx, (at, color.into()));
+        self
+    }
+
+    /// Returns the geometry of the gradient.
+    pub fn geometry(&self) -> GradientGeometry {
+        self.geometry
+    }
+
+    /// Returns the color at the position along the gradient, interpolated in the
+    /// [global color space](crate::global_color_space). Positions before the first and
+    /// after the last stop take the color of that stop.
+    ///
+    /// # Returns
+    /// * The color at `t`, or `Color::Reset` if there are no stops.
+    pub fn color_at(&self, t: f32) -> Color {
+        let space = global_color_space();
+        self.color_at_with(t, |from, to, alpha| space.lerp(from, to, alpha))
+    }
+
+    /// Returns the color of the cell at `pos`, with the gradient laid out over `area`.
+    pub fn color_in(&self, area: Rect, pos: Position) -> Color {
+        self.color_at(self.position(area, pos))
+    }
+
+    /// Like [color_at](Gradient::color_at), but interpolating between stops with `lerp`,
+    /// e.g. to blend colors in an effect's own color space.
+    pub(crate) fn color_at_with(&self, t: f32, lerp: impl Fn(&Color, &Color, f32) -> Color) -> Color {
+        let idx = self.stops.partition_point(|(stop, _)| *stop < t);
+        let prev = idx.checked_sub(1).and_then(|i| self.stops.get(i));
+
+        match (prev, self.stops.get(idx)) {
+            (None, None)                                        => Color::Reset,
+            (Some((_, color)), None) | (None, Some((_, color))) => *color,
+            (Some((a, from)), Some((b, to)))                    => lerp(from, to, (t - a) / (b - a)),
+        }
+    }
+
+    /// Returns the position along the gradient, from `0.0` to `1.0`, of the center of the
+    /// cell at `pos`, with the gradient laid out over `area`.
+    pub fn position(&self, area: Rect, pos: Position) -> f32 {
+        self.geometry.position(area, pos)
+    }
+}
+
+impl GradientGeometry {
+    /// Returns the position, from `0.0` to `1.0`, of the center of the cell at `pos`,
+    /// with the geometry laid out over `area`.
+    pub fn position(&self, area: Rect, pos: Position) -> f32 {
+        let (x, y) = scaled(area, pos);
+        let (w, h) = scaled_size(area);
+
+        let t = match *self {
+            GradientGeometry::Linear { angle } => {
+                let (min, max) = linear_bounds(angle, w, h);
+                if max > min { (project(angle, x, y) - min) / (max - min) } else { 0.0 }
+            },
+            GradientGeometry::Radial { center } => {
+                let radius = radial_extent(center, w, h);
+                let (cx, cy) = (center.0 * w, center.1 * h);
+                if radius > 0.0 { (x - cx).hypot(y - cy) / radius } else { 0.0 }
+            },
+            GradientGeometry::Angular { center: (cx, cy), start_angle } => {
+                let angle = (y - cy * h).atan2(x - cx * w).to_degrees();
+                (angle - start_angle).rem_euclid(360.0) / 360.0
+            },
+        };
+
+        t.clamp(0.0, 1.0)
+    }
+
+    /// Returns the length of the geometry over `area`, measured in columns: the extent
+    /// of the area along the line, the distance from the center to the farthest corner,
+    /// or the perimeter of the area.
+    pub(crate) fn extent(&self, area: Rect) -> f32 {
+        let (w, h) = scaled_size(area);
+        match *self {
+            GradientGeometry::Linear { angle } => {
+                let (min, max) = linear_bounds(angle, w, h);
+                max - min
+            },
+            GradientGeometry::Radial { center }     => radial_extent(center, w, h),
+            GradientGeometry::Angular { .. }        => 2.0 * (w + h),
+        }
+    }
+}
+
+/// Returns the center of the cell at `pos`, relative to the area and with the vertical
+/// axis scaled by the cell aspect ratio.
+pub(crate) fn scaled(area: Rect, pos: Position) -> (f32, f32) {
+    let x = pos.x as f32 + 0.5 - area.x as f32;
+    let y = pos.y as f32 + 0.5 - area.y as f32;
+    (x, y * CELL_ASPECT)
+}
+
+pub(crate) fn scaled_size(area: Rect) -> (f32, f32) {
+    (area.width as f32, area.height as f32 * CELL_ASPECT)
+}
+
+fn project(angle: f32, x: f32, y: f32) -> f32 {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    x * cos + y * sin
+}
+
+fn linear_bounds(angle: f32, w: f32, h: f32) -> (f32, f32) {
+    [(0.0, 0.0), (w, 0.0), (0.0, h), (w, h)].iter()
+        .map(|(x, y)| project(angle, *x, *y))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| (min.min(p), max.max(p)))
+}
+
+fn radial_extent(center: (f32, f32), w: f32, h: f32) -> f32 {
+    let (cx, cy) = (center.0 * w, center.1 * h);
+    [(0.0, 0.0), (w, 0.0), (0.0, h), (w, h)].iter()
+        .map(|(x, y)| (x - cx).hypot(y - cy))
+        .fold(0.0, f32::max)
+}
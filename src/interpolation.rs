@@ -1,7 +1,37 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use ratatui::layout::Rect;
 use ratatui::style::{Color, Style};
-use simple_easing::{back_in, back_in_out, back_out, bounce_in, bounce_in_out, bounce_out, circ_in, circ_in_out, circ_out, cubic_in, elastic_in, elastic_in_out, elastic_out, expo_in, expo_in_out, expo_out, quad_in, quad_in_out, quad_out, quart_in, quart_in_out, quart_out, quint_in, quint_in_out, quint_out, reverse, sine_in, sine_in_out, sine_out};
 
+use crate::color_space::global_color_space;
+use crate::palette::resolve;
+use simple_easing::{back_in, back_in_out, back_out, bounce_in, bounce_in_out, bounce_out, circ_in, circ_in_out, circ_out, cubic_in, cubic_in_out, cubic_out, elastic_in, elastic_in_out, elastic_out, expo_in, expo_in_out, expo_out, quad_in, quad_in_out, quad_out, quart_in, quart_in_out, quart_out, quint_in, quint_in_out, quint_out, reverse, sine_in, sine_in_out, sine_out};
+
+/// Easing functions, mapping the linear progress of a timer to the alpha applied by
+/// an effect. Besides the named curves, CSS timing functions can be reproduced with
+/// [CubicBezier](Interpolation::CubicBezier) and [Steps](Interpolation::Steps), and any
+/// interpolation can be parsed from a CSS timing string, e.g. `"ease-in-out"` or
+/// `"cubic-bezier(0.4, 0, 0.2, 1)"`.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{Interpolation, StepPosition};
+///
+/// let ease: Interpolation = "ease-in-out".parse().unwrap();
+/// assert_eq!(ease, Interpolation::CubicBezier(0.42, 0.0, 0.58, 1.0));
+///
+/// let steps: Interpolation = "steps(4, jump-start)".parse().unwrap();
+/// assert_eq!(steps, Interpolation::Steps(4, StepPosition::JumpStart));
+/// assert_eq!(steps.alpha(0.3), 0.5);
+///
+/// // non-capturing closures coerce to a custom easing function
+/// let custom = Interpolation::Custom(|a| a * a * a);
+/// assert_eq!(custom.alpha(0.5), 0.125);
+/// ```
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Interpolation {
     BackIn,
     BackOut,
@@ -47,6 +77,53 @@ pub enum Interpolation {
     SineIn,
     SineOut,
     SineInOut,
+
+    /// A CSS `cubic-bezier(x1, y1, x2, y2)` timing function; the curve runs from
+    /// `(0, 0)` to `(1, 1)`. The x coordinates are clamped to `0.0..=1.0`.
+    CubicBezier(f32, f32, f32, f32),
+
+    /// A CSS `steps(n, position)` timing function, advancing in `n` discrete steps.
+    Steps(u16, StepPosition),
+
+    /// A damped spring released at `0.0` and coming to rest at `1.0`, with the motion
+    /// until it settles compressed into the duration of the timer. A spring with low
+    /// damping relative to its stiffness overshoots and oscillates before settling.
+    Spring { stiffness: f32, damping: f32 },
+
+    /// A user-supplied easing function; non-capturing closures coerce to `fn`.
+    /// Custom interpolations cannot be serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(fn(f32) -> f32),
+}
+
+impl PartialEq for Interpolation {
+    fn eq(&self, other: &Self) -> bool {
+        use Interpolation::*;
+
+        match (self, other) {
+            (CubicBezier(a1, b1, c1, d1), CubicBezier(a2, b2, c2, d2)) => (a1, b1, c1, d1) == (a2, b2, c2, d2),
+            (Steps(n1, p1), Steps(n2, p2))                             => (n1, p1) == (n2, p2),
+            (Spring { stiffness: s1, damping: d1 }, Spring { stiffness: s2, damping: d2 }) => (s1, d1) == (s2, d2),
+            (Custom(f1), Custom(f2))                                   => *f1 as usize == *f2 as usize,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+/// Where the jumps of a [Steps](Interpolation::Steps) interpolation occur, mirroring
+/// the step positions of CSS.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepPosition {
+    /// The first jump happens at the start.
+    JumpStart,
+    /// The last jump happens at the end.
+    #[default]
+    JumpEnd,
+    /// No jump at either end; the first and last steps hold `0.0` and `1.0`.
+    JumpNone,
+    /// Jumps at both the start and the end.
+    JumpBoth,
 }
 
 impl Interpolation {
@@ -66,8 +143,8 @@ impl Interpolation {
             Interpolation::CircInOut    => circ_in_out(a),
 
             Interpolation::CubicIn      => cubic_in(a),
-            Interpolation::CubicOut     => circ_out(a),
-            Interpolation::CubicInOut   => circ_in_out(a),
+            Interpolation::CubicOut     => cubic_out(a),
+            Interpolation::CubicInOut   => cubic_in_out(a),
 
             Interpolation::ElasticIn    => elastic_in(a),
             Interpolation::ElasticOut   => elastic_out(a),
@@ -96,6 +173,216 @@ impl Interpolation {
             Interpolation::SineIn       => sine_in(a),
             Interpolation::SineOut      => sine_out(a),
             Interpolation::SineInOut    => sine_in_out(a),
+
+            Interpolation::CubicBezier(x1, y1, x2, y2) => cubic_bezier(*x1, *y1, *x2, *y2, a),
+            Interpolation::Steps(n, position)          => steps(*n, *position, a),
+            Interpolation::Spring { stiffness, damping } => spring(*stiffness, *damping, a),
+            Interpolation::Custom(f)                   => f(a),
+        }
+    }
+}
+
+/// Solves the bezier curve for the point at `x = a`, returning its y coordinate.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, a: f32) -> f32 {
+    if a <= 0.0 || a >= 1.0 {
+        return a.clamp(0.0, 1.0);
+    }
+
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    let bezier = |p1: f32, p2: f32, t: f32| {
+        let u = 1.0 - t;
+        3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+    };
+
+    // x(t) is monotonic for x coordinates within 0..=1, so bisect for t
+    let (mut lo, mut hi) = (0.0, 1.0);
+    let mut t = a;
+    for _ in 0..32 {
+        let x = bezier(x1, x2, t);
+        if (x - a).abs() < 1e-6 {
+            break;
+        }
+
+        if x < a { lo = t } else { hi = t }
+        t = (lo + hi) / 2.0;
+    }
+
+    bezier(y1, y2, t)
+}
+
+fn steps(n: u16, position: StepPosition, a: f32) -> f32 {
+    let n = n.max(1) as f32;
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => n,
+        StepPosition::JumpNone                          => (n - 1.0).max(1.0),
+        StepPosition::JumpBoth                          => n + 1.0,
+    };
+
+    let mut step = (a * n).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+
+    step.clamp(0.0, jumps) / jumps
+}
+
+/// Evaluates a damped harmonic oscillator of unit mass, released from a displacement
+/// of `1.0`. The alpha is scaled to the time it takes for the displacement to decay
+/// below 0.1%, even when critically damped.
+fn spring(stiffness: f32, damping: f32, a: f32) -> f32 {
+    if a <= 0.0 || a >= 1.0 {
+        return a.clamp(0.0, 1.0);
+    }
+
+    let omega = stiffness.max(f32::EPSILON).sqrt();
+    let zeta = damping.max(0.0) / (2.0 * omega);
+
+    // the slowest decaying term determines when the spring settles
+    let decay = match zeta {
+        z if z > 1.0 => omega * (z - (z * z - 1.0).sqrt()),
+        z            => omega * z,
+    };
+    // solves e^-x * (1 + x) = 0.001
+    const SETTLE: f32 = 9.233;
+    let t = a * SETTLE / decay.max(1e-3);
+
+    let displacement = if zeta < 1.0 {
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        (-zeta * omega * t).exp()
+            * ((omega_d * t).cos() + (zeta * omega / omega_d) * (omega_d * t).sin())
+    } else if zeta == 1.0 {
+        (-omega * t).exp() * (1.0 + omega * t)
+    } else {
+        let root = (zeta * zeta - 1.0).sqrt();
+        let (r1, r2) = (-omega * (zeta - root), -omega * (zeta + root));
+        (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r2 - r1)
+    };
+
+    1.0 - displacement
+}
+
+/// An error returned when parsing an [Interpolation] or [StepPosition] from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseInterpolationError {
+    message: String,
+}
+
+impl ParseInterpolationError {
+    fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl Display for ParseInterpolationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ParseInterpolationError {}
+
+impl FromStr for Interpolation {
+    type Err = ParseInterpolationError;
+
+    /// Parses the name of a variant, e.g. `QuadOut`, or a CSS timing function: `linear`,
+    /// `ease`, `ease-in`, `ease-out`, `ease-in-out`, `step-start`, `step-end`,
+    /// `cubic-bezier(x1, y1, x2, y2)` and `steps(n[, position])`. Springs are written as
+    /// `spring(stiffness, damping)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Interpolation::*;
+
+        let s = s.trim();
+        if let Some((name, args)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            let args: Vec<&str> = args.split(',').map(str::trim).collect();
+            let float = |idx: usize| args[idx].parse::<f32>()
+                .map_err(|_| ParseInterpolationError::new(format!("invalid number '{}' in '{s}'", args[idx])));
+
+            let arity = |n: &[usize]| match n.contains(&args.len()) {
+                true  => Ok(()),
+                false => Err(ParseInterpolationError::new(format!("wrong number of arguments in '{s}'"))),
+            };
+
+            return match name.trim() {
+                "cubic-bezier" | "CubicBezier" => {
+                    arity(&[4])?;
+                    Ok(CubicBezier(float(0)?, float(1)?, float(2)?, float(3)?))
+                },
+                "steps" | "Steps" => {
+                    arity(&[1, 2])?;
+                    let n = args[0].parse::<u16>()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| ParseInterpolationError::new(format!("invalid step count '{}' in '{s}'", args[0])))?;
+                    let position = args.get(1).map_or(Ok(StepPosition::default()), |p| p.parse())?;
+                    Ok(Steps(n, position))
+                },
+                "spring" | "Spring" => {
+                    arity(&[2])?;
+                    Ok(Spring { stiffness: float(0)?, damping: float(1)? })
+                },
+                name => Err(ParseInterpolationError::new(format!("unknown interpolation '{name}'"))),
+            };
+        }
+
+        let interpolation = match s {
+            "BackIn"       => BackIn,
+            "BackOut"      => BackOut,
+            "BackInOut"    => BackInOut,
+            "BounceIn"     => BounceIn,
+            "BounceOut"    => BounceOut,
+            "BounceInOut"  => BounceInOut,
+            "CircIn"       => CircIn,
+            "CircOut"      => CircOut,
+            "CircInOut"    => CircInOut,
+            "CubicIn"      => CubicIn,
+            "CubicOut"     => CubicOut,
+            "CubicInOut"   => CubicInOut,
+            "ElasticIn"    => ElasticIn,
+            "ElasticOut"   => ElasticOut,
+            "ElasticInOut" => ElasticInOut,
+            "ExpoIn"       => ExpoIn,
+            "ExpoOut"      => ExpoOut,
+            "ExpoInOut"    => ExpoInOut,
+            "Linear"       => Linear,
+            "QuadIn"       => QuadIn,
+            "QuadOut"      => QuadOut,
+            "QuadInOut"    => QuadInOut,
+            "QuartIn"      => QuartIn,
+            "QuartOut"     => QuartOut,
+            "QuartInOut"   => QuartInOut,
+            "QuintIn"      => QuintIn,
+            "QuintOut"     => QuintOut,
+            "QuintInOut"   => QuintInOut,
+            "Reverse"      => Reverse,
+            "SineIn"       => SineIn,
+            "SineOut"      => SineOut,
+            "SineInOut"    => SineInOut,
+
+            "linear"       => Linear,
+            "ease"         => CubicBezier(0.25, 0.1, 0.25, 1.0),
+            "ease-in"      => CubicBezier(0.42, 0.0, 1.0, 1.0),
+            "ease-out"     => CubicBezier(0.0, 0.0, 0.58, 1.0),
+            "ease-in-out"  => CubicBezier(0.42, 0.0, 0.58, 1.0),
+            "step-start"   => Steps(1, StepPosition::JumpStart),
+            "step-end"     => Steps(1, StepPosition::JumpEnd),
+            _              => return Err(ParseInterpolationError::new(format!("unknown interpolation '{s}'"))),
+        };
+
+        Ok(interpolation)
+    }
+}
+
+impl FromStr for StepPosition {
+    type Err = ParseInterpolationError;
+
+    /// Parses a CSS step position, e.g. `jump-start` or `end`, or the name of a variant.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "jump-start" | "start" | "JumpStart" => Ok(StepPosition::JumpStart),
+            "jump-end" | "end" | "JumpEnd"       => Ok(StepPosition::JumpEnd),
+            "jump-none" | "JumpNone"             => Ok(StepPosition::JumpNone),
+            "jump-both" | "JumpBoth"             => Ok(StepPosition::JumpBoth),
+            s => Err(ParseInterpolationError::new(format!("unknown step position '{s}'"))),
         }
     }
 }
@@ -127,16 +414,53 @@ impl Interpolatable<i16> for i16 {
     }
 }
 
+impl Interpolatable<u32> for u32 {
+    fn lerp(&self, target: &u32, alpha: f32) -> u32 {
+        (*self as f64).lerp(
+            &(*target as f64),
+            alpha
+        ).round() as u32
+    }
+}
+
 impl Interpolatable<f32> for f32 {
     fn lerp(&self, target: &f32, alpha: f32) -> f32 {
         self + (target - self) * alpha
     }
 }
 
+impl Interpolatable<f64> for f64 {
+    fn lerp(&self, target: &f64, alpha: f32) -> f64 {
+        self + (target - self) * alpha as f64
+    }
+}
+
+impl<const N: usize> Interpolatable<[f32; N]> for [f32; N] {
+    fn lerp(&self, target: &[f32; N], alpha: f32) -> [f32; N] {
+        let mut lerped = *self;
+        lerped.iter_mut()
+            .zip(target)
+            .for_each(|(v, target)| *v = v.lerp(target, alpha));
+
+        lerped
+    }
+}
+
+impl Interpolatable<Rect> for Rect {
+    fn lerp(&self, target: &Rect, alpha: f32) -> Rect {
+        Rect::new(
+            self.x.lerp(&target.x, alpha),
+            self.y.lerp(&target.y, alpha),
+            self.width.lerp(&target.width, alpha),
+            self.height.lerp(&target.height, alpha),
+        )
+    }
+}
+
 impl Interpolatable<Style> for Style {
     fn lerp(&self, target: &Style, alpha: f32) -> Style {
-        let fg = self.fg.lerp(&target.fg, alpha);
-        let bg = self.bg.lerp(&target.bg, alpha);
+        let fg = lerp_fg(self.fg, target.fg, alpha);
+        let bg = lerp_bg(self.bg, target.bg, alpha);
 
         let mut s = *self;
         if let Some(fg) = fg { s = s.fg(fg) }
@@ -146,32 +470,53 @@ impl Interpolatable<Style> for Style {
     }
 }
 
+/// Interpolates in the [global color space](crate::global_color_space).
 impl Interpolatable<Color> for Color {
     fn lerp(&self, target: &Color, alpha: f32) -> Color {
-        if alpha == 0.0 {
-            return *self;
-        } else if alpha == 1.0 {
-            return *target;
-        }
-        
-        let (h, s, v) = self.to_hsl();
-        let (h2, s2, v2) = target.to_hsl();
-        Color::from_hsl(
-            h.lerp(&h2, alpha) as f64,
-            s.lerp(&s2, alpha) as f64,
-            v.lerp(&v2, alpha) as f64,
-        )
+        global_color_space().lerp(self, target, alpha)
     }
 }
 
+/// Interpolates foreground colors, see [lerp_fg]; background colors are interpolated
+/// with [lerp_bg].
 impl Interpolatable<Option<Color>> for Option<Color> {
     fn lerp(&self, target: &Option<Color>, alpha: f32) -> Option<Color> {
-        match (self, target) {
-            (Some(c1), Some(c2)) => Some(c1.lerp(c2, alpha)),
-            (Some(c1), None)     => Some(*c1),
-            (None,     Some(c2)) => Some(*c2),
-            (None,     None)     => None,
-        }
+        lerp_fg(*self, *target, alpha)
+    }
+}
+
+/// Interpolates between two optional foreground colors in the
+/// [global color space](crate::global_color_space), with `Color::Reset` resolving to
+/// the default foreground color. A missing color takes on the other color.
+pub fn lerp_fg(from: Option<Color>, to: Option<Color>, alpha: f32) -> Option<Color> {
+    let space = global_color_space();
+    lerp_optional(from, to, |a, b| space.lerp(&a, &b, alpha))
+}
+
+/// Interpolates between two optional background colors in the
+/// [global color space](crate::global_color_space), with `Color::Reset` resolving to
+/// the default background color. A missing color takes on the other color.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{lerp_bg, lerp_fg};
+///
+/// let black = Some(Color::Rgb(0, 0, 0));
+/// assert_eq!(lerp_bg(Some(Color::Reset), black, 0.5), Some(Color::Rgb(0, 0, 0)));
+/// assert_ne!(lerp_fg(Some(Color::Reset), black, 0.5), Some(Color::Rgb(0, 0, 0)));
+/// ```
+pub fn lerp_bg(from: Option<Color>, to: Option<Color>, alpha: f32) -> Option<Color> {
+    let space = global_color_space();
+    lerp_optional(from, to, |a, b| space.lerp_bg(&a, &b, alpha))
+}
+
+fn lerp_optional(a: Option<Color>, b: Option<Color>, lerp: impl Fn(Color, Color) -> Color) -> Option<Color> {
+    match (a, b) {
+        (Some(c1), Some(c2)) => Some(lerp(c1, c2)),
+        (Some(c1), None)     => Some(c1),
+        (None,     Some(c2)) => Some(c2),
+        (None,     None)     => None,
     }
 }
 
@@ -199,7 +544,7 @@ impl HslConvertable for Color {
                 let hsl: colorsys::Hsl = rgb.as_ref().into();
                 (hsl.hue() as f32, hsl.saturation() as f32, hsl.lightness() as f32)
             }
-            _ => (0.0, 0.0, 0.0)
+            color => resolve(*color, false).to_hsl(),
         }
     }
 }
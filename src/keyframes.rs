@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use crate::interpolation::{Interpolatable, Interpolation};
+
+/// A value animated through any number of stops, each reached at a given time. Every
+/// segment between two stops is eased with the interpolation of the stop it leads to.
+///
+/// Keyframes can drive a [Tween](crate::Tween), or parameterize effects such as
+/// [fade_keyframes](crate::fx::fade_keyframes) and [hsl_shift_keyframes](crate::fx::hsl_shift_keyframes).
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use tachyonfx::{Interpolation, Keyframes};
+///
+/// let width = Keyframes::new()
+///     .stop(0, 10u16, Interpolation::Linear)
+///     .stop(200, 50, Interpolation::Linear)
+///     .stop(600, 30, Interpolation::QuadOut);
+///
+/// assert_eq!(width.duration(), Duration::from_millis(600));
+/// assert_eq!(width.value_at(Duration::from_millis(100)), Some(30));
+/// assert_eq!(width.value_at(Duration::from_millis(200)), Some(50));
+/// assert_eq!(width.value_at(Duration::from_secs(1)), Some(30));
+///
+/// // the origin is used as the value at the start of the first segment
+/// let offset = Keyframes::new().stop(100, 10u16, Interpolation::Linear);
+/// assert_eq!(offset.value_from(&0, Duration::from_millis(50)), 5);
+/// ```
+#[derive(Clone)]
+pub struct Keyframes<T> {
+    stops: Vec<Keyframe<T>>,
+}
+
+#[derive(Clone)]
+struct Keyframe<T> {
+    at: Duration,
+    value: T,
+    interpolation: Interpolation,
+}
+
+impl<T> Keyframes<T>
+    where T: Interpolatable<T> + Clone
+{
+    /// Creates an empty set of keyframes.
+    pub fn new() -> Self {
+        Self { stops: Vec::new() }
+    }
+
+    /// Adds a stop, reached at `at_ms` milliseconds. Stops may be added in any order.
+    ///
+    /// # Arguments
+    /// * `at_ms` - The time of the stop, in milliseconds.
+    /// * `value` - The value at the stop.
+    /// * `interpolation` - The easing of the segment leading up to the stop.
+    ///
+    /// # Returns
+    /// * The keyframes with the added stop.
+    pub fn stop(mut self, at_ms: u32, value: T, interpolation: Interpolation) -> Self {
+        let at = Duration::from_millis(at_ms as u64);
+        let idx = self.stops.partition_point(|stop| stop.at <= at);
+        self.stops.insert(idx, Keyframe { at, value, interpolation });
+        self
+    }
+
+    /// Returns the time of the last stop.
+    pub fn duration(&self) -> Duration {
+        self.stops.last().map_or(Duration::ZERO, |stop| stop.at)
+    }
+
+    /// Returns the number of stops.
+    pub fn len(&self) -> usize {
+        self.stops.len()
+    }
+
+    /// Returns true if there are no stops.
+    pub fn is_empty(&self) -> bool {
+        self.stops.is_empty()
+    }
+
+    /// Returns the value of the first stop.
+    pub fn first(&self) -> Option<&T> {
+        self.stops.first().map(|stop| &stop.value)
+    }
+
+    /// Returns the value of the last stop.
+    pub fn last(&self) -> Option<&T> {
+        self.stops.last().map(|stop| &stop.value)
+    }
+
+    /// Returns the value at the specified time. The value of the first stop is held until
+    /// it is reached, and the value of the last stop once it has been passed.
+    ///
+    /// # Returns
+    /// * The value at `elapsed`, or `None` if there are no stops.
+    pub fn value_at(&self, elapsed: Duration) -> Option<T> {
+        self.stops.first()
+            .map(|first| self.value_from(&first.value, elapsed))
+    }
+
+    /// Returns the value at the specified time, with `origin` as the value at the start.
+    /// The first segment leads from `origin` to the first stop.
+    ///
+    /// # Arguments
+    /// * `origin` - The value at the start, e.g. the original color of a cell.
+    /// * `elapsed` - The time to sample the value at.
+    pub fn value_from(&self, origin: &T, elapsed: Duration) -> T {
+        self.value_from_with(origin, elapsed, T::lerp)
+    }
+
+    /// Like [value_from](Keyframes::value_from), but interpolating each segment with `lerp`,
+    /// e.g. to blend colors in an effect's own color space.
+    pub(crate) fn value_from_with(
+        &self,
+        origin: &T,
+        elapsed: Duration,
+        lerp: impl Fn(&T, &T, f32) -> T,
+    ) -> T {
+        let idx = self.stops.partition_point(|stop| stop.at < elapsed);
+        let Some(next) = self.stops.get(idx) else {
+            return self.stops.last().map_or_else(|| origin.clone(), |stop| stop.value.clone());
+        };
+
+        let (start, from) = match idx {
+            0 => (Duration::ZERO, origin),
+            _ => (self.stops[idx - 1].at, &self.stops[idx - 1].value),
+        };
+
+        let segment = (next.at - start).as_secs_f32();
+        let alpha = match segment {
+            0.0 => 1.0,
+            _   => (elapsed - start).as_secs_f32() / segment,
+        };
+
+        lerp(from, &next.value, next.interpolation.alpha(alpha))
+    }
+}
+
+impl<T> Default for Keyframes<T>
+    where T: Interpolatable<T> + Clone
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -4,25 +4,57 @@
 //! appeal of terminal applications, offering capabilities such as color transformations,
 //! animations, and complex effect combinations.
 
+extern crate self as tachyonfx;
+
 mod interpolation;
+mod keyframes;
+mod noise;
+mod pattern;
 mod effect;
+mod effect_manager;
 mod shader;
 mod effect_timer;
+mod gradient;
 mod cell_iter;
 mod color_mapper;
+mod color_depth;
+mod color_space;
+mod palette;
 mod color_ext;
 mod rect_ext;
 mod render_effect;
+mod tween;
 
+pub mod dsl;
 pub mod fx;
+pub mod rng;
+#[cfg(feature = "serde")]
+pub mod descriptor;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 /// `CellIterator` provides an iterator over terminal cells.
 pub use cell_iter::CellIterator;
 pub use color_mapper::ColorMapper;
+pub use color_depth::{ColorDepth, global_color_depth, global_dithering, quantize_to_global_depth, set_global_color_depth, set_global_dithering};
+pub use color_space::{ColorSpace, global_color_space, set_global_color_space};
 pub use effect::{Effect, CellFilter, IntoEffect};
-pub use effect_timer::EffectTimer;
+pub use effect_manager::EffectManager;
+pub use effect_timer::{EffectTimer, global_time_scale, set_global_time_scale};
+pub use gradient::{Gradient, GradientGeometry};
+pub use keyframes::Keyframes;
+pub use pattern::Pattern;
+pub use palette::{Palette, global_palette, set_global_palette};
 pub use rect_ext::CenteredShrink;
 pub use render_effect::EffectRenderer;
 pub use shader::Shader;
+pub use tween::Tween;
+pub use tachyonfx_derive::Shader;
 pub use interpolation::*;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use std::time::Duration;
+    pub use ratatui::buffer::Buffer;
+    pub use ratatui::layout::Rect;
+}
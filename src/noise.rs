@@ -0,0 +1,79 @@
+/// Hashes the integer coordinates and seed to a value in `0.0..1.0`.
+pub(crate) fn hash(x: i32, y: i32, seed: u64) -> f32 {
+    let packed = (x as u32 as u64) << 32 | y as u32 as u64;
+    let h = mix(mix(seed) ^ packed);
+
+    (h >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// The splitmix64 finalizer.
+fn mix(mut h: u64) -> u64 {
+    h = h.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^ (h >> 31)
+}
+
+/// Smoothly interpolated value noise, in `0.0..1.0`, with features about one unit apart.
+pub(crate) fn value_noise(x: f32, y: f32, seed: u64) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (smoothstep(x - x0), smoothstep(y - y0));
+    let (x0, y0) = (x0 as i32, y0 as i32);
+
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    let top = lerp(hash(x0, y0, seed), hash(x0 + 1, y0, seed), fx);
+    let bottom = lerp(hash(x0, y0 + 1, seed), hash(x0 + 1, y0 + 1, seed), fx);
+
+    lerp(top, bottom, fy)
+}
+
+/// Perlin gradient noise, in `0.0..1.0`, with features about one unit apart.
+pub(crate) fn perlin(x: f32, y: f32, seed: u64) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (dx, dy) = (x - x0, y - y0);
+    let (x0, y0) = (x0 as i32, y0 as i32);
+
+    let gradient = |ix: i32, iy: i32, dx: f32, dy: f32| {
+        let angle = hash(ix, iy, seed) * std::f32::consts::TAU;
+        let (sin, cos) = angle.sin_cos();
+        dx * cos + dy * sin
+    };
+
+    let (fx, fy) = (fade(dx), fade(dy));
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    let top = lerp(gradient(x0, y0, dx, dy), gradient(x0 + 1, y0, dx - 1.0, dy), fx);
+    let bottom = lerp(gradient(x0, y0 + 1, dx, dy - 1.0), gradient(x0 + 1, y0 + 1, dx - 1.0, dy - 1.0), fx);
+
+    // the range of 2D perlin noise is about ±0.71
+    (lerp(top, bottom, fy) * std::f32::consts::FRAC_1_SQRT_2 + 0.5).clamp(0.0, 1.0)
+}
+
+/// Fractal noise, summing `octaves` layers of `noise` at doubling frequencies and
+/// halving amplitudes; normalized to `0.0..1.0`.
+pub(crate) fn fbm(
+    noise: fn(f32, f32, u64) -> f32,
+    x: f32,
+    y: f32,
+    seed: u64,
+    octaves: u32,
+) -> f32 {
+    let (mut sum, mut amplitude, mut frequency, mut norm) = (0.0, 1.0, 1.0, 0.0);
+    for octave in 0..octaves.max(1) {
+        let seed = seed.wrapping_add(octave as u64);
+        sum += amplitude * noise(x * frequency, y * frequency, seed);
+        norm += amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+
+    sum / norm
+}
+
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Perlin's quintic fade curve.
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
@@ -0,0 +1,176 @@
+use std::sync::RwLock;
+
+use ratatui::style::Color;
+
+/// The global palette, consulted whenever a color other than `Color::Rgb` is interpolated.
+static GLOBAL_PALETTE: RwLock<Palette> = RwLock::new(Palette::xterm());
+
+/// The RGB values of the terminal's colors, used to resolve named and indexed colors,
+/// and `Color::Reset`, before they are interpolated. Defaults to the xterm palette;
+/// override it with the actual colors of the terminal theme for accurate fades.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::Palette;
+///
+/// let palette = Palette::xterm()
+///     .with_ansi(Color::Red, (0xfb, 0x49, 0x34))
+///     .with_default_bg((0x28, 0x28, 0x28));
+///
+/// assert_eq!(palette.resolve_fg(Color::Red), Color::Rgb(0xfb, 0x49, 0x34));
+/// assert_eq!(palette.resolve_bg(Color::Reset), Color::Rgb(0x28, 0x28, 0x28));
+/// assert_eq!(palette.resolve_fg(Color::Indexed(196)), Color::Rgb(255, 0, 0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Palette {
+    ansi: [(u8, u8, u8); 16],
+    default_fg: (u8, u8, u8),
+    default_bg: (u8, u8, u8),
+}
+
+/// Sets the global palette, used by all effects to resolve colors which aren't `Color::Rgb`.
+///
+/// # Arguments
+/// * `palette` - The palette of the terminal.
+pub fn set_global_palette(palette: Palette) {
+    *GLOBAL_PALETTE.write().unwrap_or_else(|e| e.into_inner()) = palette;
+}
+
+/// Returns the global palette.
+pub fn global_palette() -> Palette {
+    *GLOBAL_PALETTE.read().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Palette {
+    /// Returns the default xterm palette, with light gray text on a black background.
+    pub const fn xterm() -> Self {
+        Self {
+            ansi: [
+                (0, 0, 0),       (205, 0, 0),     (0, 205, 0),     (205, 205, 0),
+                (0, 0, 238),     (205, 0, 205),   (0, 205, 205),   (229, 229, 229),
+                (127, 127, 127), (255, 0, 0),     (0, 255, 0),     (255, 255, 0),
+                (92, 92, 255),   (255, 0, 255),   (0, 255, 255),   (255, 255, 255),
+            ],
+            default_fg: (229, 229, 229),
+            default_bg: (0, 0, 0),
+        }
+    }
+
+    /// Overrides one of the 16 ANSI colors, given as a named color or as `Color::Indexed`
+    /// with an index below 16; any other color is ignored.
+    ///
+    /// # Arguments
+    /// * `color` - The ANSI color to override.
+    /// * `rgb` - The RGB value of the color.
+    pub fn with_ansi(mut self, color: Color, rgb: (u8, u8, u8)) -> Self {
+        if let Some(idx) = ansi_index(color).filter(|idx| *idx < 16) {
+            self.ansi[idx as usize] = rgb;
+        }
+        self
+    }
+
+    /// Sets the color which `Color::Reset` resolves to for foreground colors.
+    pub fn with_default_fg(mut self, rgb: (u8, u8, u8)) -> Self {
+        self.default_fg = rgb;
+        self
+    }
+
+    /// Sets the color which `Color::Reset` resolves to for background colors.
+    pub fn with_default_bg(mut self, rgb: (u8, u8, u8)) -> Self {
+        self.default_bg = rgb;
+        self
+    }
+
+    /// Resolves a foreground color to `Color::Rgb`.
+    pub fn resolve_fg(&self, color: Color) -> Color {
+        self.resolve(color, self.default_fg)
+    }
+
+    /// Resolves a background color to `Color::Rgb`.
+    pub fn resolve_bg(&self, color: Color) -> Color {
+        self.resolve(color, self.default_bg)
+    }
+
+    fn resolve(&self, color: Color, reset: (u8, u8, u8)) -> Color {
+        let (r, g, b) = match color {
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Reset        => reset,
+            color               => self.indexed(ansi_index(color).unwrap()),
+        };
+
+        Color::Rgb(r, g, b)
+    }
+
+    /// Returns the RGB value of the color at `idx` in the 256-color palette.
+    pub(crate) fn indexed(&self, idx: u8) -> (u8, u8, u8) {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        match idx {
+            0..=15   => self.ansi[idx as usize],
+            16..=231 => {
+                let idx = idx - 16;
+                let level = |i: u8| CUBE_LEVELS[i as usize];
+                (level(idx / 36), level(idx / 6 % 6), level(idx % 6))
+            },
+            _        => {
+                let gray = 8 + 10 * (idx - 232);
+                (gray, gray, gray)
+            },
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::xterm()
+    }
+}
+
+/// Resolves a color to `Color::Rgb` with the global palette; `Color::Reset` resolves to the
+/// default foreground or background color, depending on `bg`.
+pub(crate) fn resolve(color: Color, bg: bool) -> Color {
+    match (color, bg) {
+        (Color::Rgb(..), _) => color,
+        (_, false)          => global_palette().resolve_fg(color),
+        (_, true)           => global_palette().resolve_bg(color),
+    }
+}
+
+/// Returns the named color for one of the 16 ANSI color indices.
+pub(crate) fn ansi_color(idx: u8) -> Color {
+    const ANSI: [Color; 16] = [
+        Color::Black, Color::Red, Color::Green, Color::Yellow,
+        Color::Blue, Color::Magenta, Color::Cyan, Color::Gray,
+        Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+        Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+    ];
+
+    ANSI[idx as usize % 16]
+}
+
+/// Returns the index of a named or indexed color in the 256-color palette.
+fn ansi_index(color: Color) -> Option<u8> {
+    let idx = match color {
+        Color::Black        => 0,
+        Color::Red          => 1,
+        Color::Green        => 2,
+        Color::Yellow       => 3,
+        Color::Blue         => 4,
+        Color::Magenta      => 5,
+        Color::Cyan         => 6,
+        Color::Gray         => 7,
+        Color::DarkGray     => 8,
+        Color::LightRed     => 9,
+        Color::LightGreen   => 10,
+        Color::LightYellow  => 11,
+        Color::LightBlue    => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan    => 14,
+        Color::White        => 15,
+        Color::Indexed(idx) => idx,
+        Color::Rgb(..) | Color::Reset => return None,
+    };
+
+    Some(idx)
+}
@@ -0,0 +1,151 @@
+use ratatui::layout::{Position, Rect};
+
+use crate::gradient::{scaled, scaled_size, CELL_ASPECT};
+use crate::noise::{fbm, hash, value_noise};
+use crate::GradientGeometry;
+
+/// A spatial pattern, mapping the progress of an effect to a per-cell alpha. Each cell
+/// has a threshold in the pattern, and transitions once the progress reaches it, over
+/// a window set by the pattern's [transition](Pattern::with_transition).
+///
+/// Any effect can be played through a pattern with [fx::with_pattern](crate::fx::with_pattern),
+/// e.g. fading in from the center, or dissolving in a checkerboard.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::{Position, Rect};
+/// use tachyonfx::Pattern;
+///
+/// let area = Rect::new(0, 0, 4, 4);
+/// let checkerboard = Pattern::checkerboard(1);
+///
+/// assert_eq!(checkerboard.cell_alpha(area, Position::new(0, 0), 0.25), 1.0);
+/// assert_eq!(checkerboard.cell_alpha(area, Position::new(1, 0), 0.25), 0.0);
+/// assert_eq!(checkerboard.cell_alpha(area, Position::new(1, 0), 0.75), 1.0);
+///
+/// let sweep = Pattern::linear(0.0).with_transition(0.0);
+/// assert_eq!(sweep.cell_alpha(area, Position::new(1, 0), 0.5), 1.0);
+/// assert_eq!(sweep.cell_alpha(area, Position::new(2, 0), 0.5), 0.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pattern {
+    kind: PatternKind,
+    transition: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum PatternKind {
+    Linear { angle: f32 },
+    Radial { center: (f32, f32) },
+    Diamond { center: (f32, f32) },
+    Checkerboard { size: u16 },
+    Blinds { size: u16, horizontal: bool },
+    Noise { scale: f32, seed: u64 },
+    Random { seed: u64 },
+}
+
+impl Pattern {
+    /// Progresses along a line at `angle` degrees, clockwise from left-to-right.
+    pub fn linear(angle: f32) -> Self {
+        Self::new(PatternKind::Linear { angle }, 0.3)
+    }
+
+    /// Progresses outwards from `center`, given relative to the area.
+    pub fn radial(center: (f32, f32)) -> Self {
+        Self::new(PatternKind::Radial { center }, 0.3)
+    }
+
+    /// Progresses outwards from `center`, given relative to the area, in a diamond shape.
+    pub fn diamond(center: (f32, f32)) -> Self {
+        Self::new(PatternKind::Diamond { center }, 0.3)
+    }
+
+    /// Transitions the squares of a checkerboard with squares of `size` cells in two
+    /// halves: the first half of the progress covers the dark squares, the second half the
+    /// light squares.
+    pub fn checkerboard(size: u16) -> Self {
+        Self::new(PatternKind::Checkerboard { size: size.max(1) }, 0.0)
+    }
+
+    /// Transitions every slat of a set of blinds at once, each slat `size` cells wide.
+    /// Horizontal slats open from top to bottom, vertical slats from left to right.
+    pub fn blinds(size: u16, horizontal: bool) -> Self {
+        Self::new(PatternKind::Blinds { size: size.max(1), horizontal }, 0.0)
+    }
+
+    /// Transitions in the order of smooth fractal noise, with features about `scale`
+    /// cells apart.
+    pub fn noise(scale: f32, seed: u64) -> Self {
+        Self::new(PatternKind::Noise { scale, seed }, 0.3)
+    }
+
+    /// Transitions each cell at a random point in time.
+    pub fn random(seed: u64) -> Self {
+        Self::new(PatternKind::Random { seed }, 0.0)
+    }
+
+    /// Sets the width of the window, relative to the full progress, over which each
+    /// cell transitions; `0.0` makes cells switch instantly once reached.
+    pub fn with_transition(self, transition: f32) -> Self {
+        Self { transition: transition.max(0.0), ..self }
+    }
+
+    fn new(kind: PatternKind, transition: f32) -> Self {
+        Self { kind, transition }
+    }
+
+    /// Returns the alpha of the cell at `pos`, given the overall progress `alpha`, with the
+    /// pattern laid out over `area`. The alpha of every cell is `0.0` at the start and `1.0`
+    /// at the end.
+    pub fn cell_alpha(&self, area: Rect, pos: Position, alpha: f32) -> f32 {
+        if alpha <= 0.0 {
+            return 0.0;
+        } else if alpha >= 1.0 {
+            return 1.0;
+        }
+
+        let threshold = self.threshold(area, pos);
+        match self.transition {
+            w if w > 0.0 => ((alpha * (1.0 + w) - threshold) / w).clamp(0.0, 1.0),
+            _            => if alpha > threshold { 1.0 } else { 0.0 },
+        }
+    }
+
+    /// Returns the point of the overall progress, from `0.0` to `1.0`, at which the cell
+    /// at `pos` starts transitioning.
+    fn threshold(&self, area: Rect, pos: Position) -> f32 {
+        let (x, y) = (pos.x.saturating_sub(area.x), pos.y.saturating_sub(area.y));
+
+        match self.kind {
+            PatternKind::Linear { angle } =>
+                GradientGeometry::Linear { angle }.position(area, pos),
+            PatternKind::Radial { center } =>
+                GradientGeometry::Radial { center }.position(area, pos),
+            PatternKind::Diamond { center } => {
+                let (px, py) = scaled(area, pos);
+                let (w, h) = scaled_size(area);
+                let (cx, cy) = (center.0 * w, center.1 * h);
+                let max = cx.max(w - cx) + cy.max(h - cy);
+
+                if max > 0.0 { ((px - cx).abs() + (py - cy).abs()) / max } else { 0.0 }
+            },
+            PatternKind::Checkerboard { size } => match (x / size + y / size) % 2 {
+                0 => 0.0,
+                _ => 0.5,
+            },
+            PatternKind::Blinds { size, horizontal } => {
+                let offset = if horizontal { y % size } else { x % size };
+                offset as f32 / size as f32
+            },
+            PatternKind::Noise { scale, seed } => {
+                let scale = scale.max(f32::EPSILON);
+                let n = fbm(value_noise, x as f32 / scale, y as f32 * CELL_ASPECT / scale, seed, 3);
+                // stretch the fractal noise, which clusters around the middle
+                ((n - 0.2) / 0.6).clamp(0.0, 1.0)
+            },
+            PatternKind::Random { seed } => hash(x as i32, y as i32, seed),
+        }
+    }
+}
@@ -2,9 +2,23 @@ use std::time::Duration;
 use ratatui::buffer::Buffer;
 use ratatui::Frame;
 use ratatui::layout::Rect;
+use crate::effect_timer::{global_time_scale, scale_duration};
 use crate::shader::Shader;
 
+/// Renders effects to a [Frame] or [Buffer].
 pub trait EffectRenderer<T> {
+    /// Advances the effect by `last_tick`, scaled by the
+    /// [global time scale](crate::global_time_scale), and renders it to `area`.
+    ///
+    /// # Color depth
+    /// The rendered colors are **not** quantized to the
+    /// [global color depth](crate::global_color_depth): effects read the colors of the
+    /// buffer, so quantizing after every effect would compound the error. Unless the
+    /// terminal supports truecolor, call
+    /// [quantize_to_global_depth](crate::quantize_to_global_depth) once after the last
+    /// effect of the frame has been rendered.
+    /// [process_effects](crate::EffectManager::process_effects) quantizes its effects
+    /// by itself.
     fn render_effect(
         &mut self,
         effect: &mut T,
@@ -43,7 +57,7 @@ fn render_effect<S: Shader>(
     last_tick: Duration,
 ) {
     effect.process(
-        last_tick,
+        scale_duration(last_tick, global_time_scale()),
         buf,
         area
     );
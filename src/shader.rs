@@ -4,11 +4,52 @@ use ratatui::layout::{Rect};
 use crate::cell_iter::CellIterator;
 
 use crate::effect::CellFilter;
+use crate::ColorSpace;
 use crate::EffectTimer;
+use crate::effect_timer::scale_duration;
 
 /// A trait representing a shader-like object that can be processed for a duration.
 /// The `Shader` trait defines the interface for objects that can apply visual effects
 /// to terminal cells over time.
+///
+/// Most of the trait can be derived with `#[derive(Shader)]`, by marking the fields
+/// holding the timer, area, cell filter and any wrapped child effect. The shader logic
+/// itself is written as inherent `execute` or `process` methods.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{CellFilter, CellIterator, EffectTimer, IntoEffect, Shader};
+///
+/// #[derive(Clone, Shader)]
+/// struct Blank {
+///     #[shader(timer)]
+///     timer: EffectTimer,
+///     #[shader(area)]
+///     area: Option<Rect>,
+///     #[shader(filter)]
+///     filter: CellFilter,
+/// }
+///
+/// impl Blank {
+///     fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+///         if alpha > 0.5 {
+///             cell_iter.for_each(|(_, cell)| { cell.set_char(' '); });
+///         }
+///     }
+/// }
+///
+/// let area = Rect::new(0, 0, 4, 1);
+/// let mut buf = Buffer::with_lines(["abcd"]);
+///
+/// let mut effect = Blank { timer: 100.into(), area: None, filter: CellFilter::All }
+///     .into_effect();
+/// effect.process(Duration::from_millis(60), &mut buf, area);
+/// assert_eq!(buf, Buffer::with_lines(["    "]));
+/// assert!(effect.running());
+/// ```
 pub trait Shader {
     /// Processes the shader for the given duration. Returns any overflowed
     /// duration if the shader has completed.
@@ -29,8 +70,9 @@ pub trait Shader {
     /// use std::time::Duration;
     /// use ratatui::buffer::Buffer;
     /// use ratatui::layout::Rect;
+    /// use tachyonfx::{fx, Shader};
     ///
-    /// let mut shader = MyShader::new();
+    /// let mut shader = fx::dissolve(100, 500);
     /// let area = Rect::new(0, 0, 10, 10);
     /// let mut buffer = Buffer::empty(area);
     /// let overflow = shader.process(Duration::from_millis(100), &mut buffer, area);
@@ -118,13 +160,21 @@ pub trait Shader {
     ///
     /// # Example
     /// ```
-    /// use tachyonfx::CellFilter;
+    /// use tachyonfx::{fx, CellFilter, Shader};
     ///
-    /// let mut shader = MyShader::new();
-    /// shader.cell_selection(CellFilter::Not(CellFilter::Text));
+    /// let mut shader = fx::dissolve(100, 500);
+    /// shader.set_cell_selection(CellFilter::Not(CellFilter::Text.into()));
     /// ```
     fn set_cell_selection(&mut self, filter: CellFilter);
 
+    /// Sets the color space used for interpolating colors, overriding the
+    /// [global color space](crate::global_color_space). Shaders which don't
+    /// interpolate colors ignore it.
+    ///
+    /// # Arguments
+    /// * `color_space` - The color space to interpolate colors in.
+    fn set_color_space(&mut self, _color_space: ColorSpace) {}
+
     /// Reverses the shader effect.
     fn reverse(&mut self) {}
 
@@ -135,13 +185,86 @@ pub trait Shader {
     ///
     /// # Example
     /// ```
-    /// let mut shader = MyShader::new();
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let mut shader = fx::dissolve(100, 500);
     /// if let Some(timer) = shader.timer_mut() {
     ///     timer.reset();
     /// }
     /// ```
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
 
+    /// Returns a reference to the shader's timer, if any.
+    ///
+    /// # Returns
+    /// * An `Option` containing a reference to the shader's `EffectTimer`, or `None` if not applicable.
+    fn timer(&self) -> Option<&EffectTimer> { None }
+
+    /// Returns the total duration of the shader effect, if it is finite and known.
+    /// The default implementation returns the duration of the shader's timer.
+    ///
+    /// # Returns
+    /// * An `Option` containing the total duration, or `None` for effects that run
+    ///   indefinitely or have no timer.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let effect = fx::sequence(vec![fx::dissolve(10, 300), fx::sleep(200)]);
+    /// assert_eq!(effect.duration(), Some(Duration::from_millis(500)));
+    /// assert_eq!(fx::repeating(effect).duration(), None);
+    /// ```
+    fn duration(&self) -> Option<Duration> {
+        self.timer().map(EffectTimer::duration)
+    }
+
+    /// Jumps the shader effect to the specified elapsed time, as measured from the
+    /// start of the effect. Composite effects route the elapsed time to their children.
+    /// The shader is applied to the buffer on the next call to `process`.
+    ///
+    /// The default implementation sets the elapsed time of the shader's timer.
+    ///
+    /// # Arguments
+    /// * `elapsed` - The elapsed time to jump to.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{fx, Shader};
+    ///
+    /// let mut effect = fx::sequence(vec![fx::dissolve(10, 300), fx::sleep(200)]);
+    /// effect.seek(Duration::from_millis(450));
+    /// assert!(effect.running());
+    ///
+    /// effect.seek(Duration::from_millis(500));
+    /// assert!(effect.done());
+    /// ```
+    fn seek(&mut self, elapsed: Duration) {
+        if let Some(timer) = self.timer_mut() {
+            timer.set_elapsed(elapsed);
+        }
+    }
+
+    /// Jumps the shader effect to the specified normalized progress, in the range
+    /// `0.0..=1.0`; NaN is treated as `0.0`. Has no effect unless the total duration
+    /// of the shader is known.
+    ///
+    /// # Arguments
+    /// * `progress` - The normalized progress to jump to.
+    fn seek_progress(&mut self, progress: f32) {
+        if let Some(duration) = self.duration() {
+            let progress = if progress.is_nan() { 0.0 } else { progress.clamp(0.0, 1.0) };
+            self.seek(scale_duration(duration, progress).min(duration));
+        }
+    }
+
+    /// Returns the number of completed iterations of a looping shader, such as
+    /// [`repeat`](crate::fx::repeat) or [`ping_pong`](crate::fx::ping_pong). Used
+    /// by [`Effect`](crate::Effect) to notify [loop hooks](crate::Effect::on_loop);
+    /// the default implementation returns `0`.
+    fn loop_count(&self) -> u32 { 0 }
 
     /// Returns the cell selection strategy for the shader, if any.
     ///
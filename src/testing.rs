@@ -0,0 +1,298 @@
+//! Golden-frame snapshot testing of effects.
+//!
+//! An effect is rendered over an input [`Buffer`] at a list of timestamps, and the
+//! resulting frames are compared against a snapshot stored in `tests/snapshots/` of
+//! the crate under test. A snapshot records the text of every frame, followed by the
+//! style of each row as runs of identically styled cells:
+//!
+//! ```text
+//! --- 250ms ---
+//! |Hello|
+//! 0: 0..2 fg=#1d2021 bg=Reset
+//! 0: 2..5 fg=Reset bg=Reset
+//! ```
+//!
+//! Mismatches fail with a line diff between the stored and the rendered frames.
+//! Missing snapshots are an error; run the tests with `TACHYONFX_UPDATE_SNAPSHOTS=1`
+//! to create or update them.
+//!
+//! # Example
+//! ```
+//! use ratatui::buffer::Buffer;
+//! use ratatui::style::Color;
+//! use tachyonfx::{fx, testing};
+//!
+//! let input = Buffer::with_lines(["hello"]);
+//! let frames = testing::render_frames(&fx::fade_to_fg(Color::Red, 100), &input, &[0, 100]);
+//!
+//! assert_eq!(testing::format_frames(&frames), "\
+//! --- 0ms ---
+//! |hello|
+//! 0: 0..5 fg=Reset bg=Reset
+//!
+//! --- 100ms ---
+//! |hello|
+//! 0: 0..5 fg=Red bg=Reset
+//! ");
+//! ```
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+
+use crate::effect::Effect;
+use crate::shader::Shader;
+
+/// Environment variable which, when set to `1`, makes [`assert_snapshot`] write the
+/// rendered frames to the snapshot file instead of comparing against it.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "TACHYONFX_UPDATE_SNAPSHOTS";
+
+/// Renders the effect over the input buffer at each of the given timestamps.
+///
+/// Every frame starts from a copy of `input`, mirroring an application which renders
+/// its widgets before applying the effect. The effect is processed for the time
+/// elapsed since the previous timestamp; the effect passed in is left untouched.
+///
+/// # Arguments
+/// * `effect` - The effect to render.
+/// * `input` - The buffer the effect is applied to.
+/// * `timestamps_ms` - The timestamps of the frames, in milliseconds, in ascending order.
+///
+/// # Returns
+/// * The timestamp and the rendered buffer of each frame.
+pub fn render_frames(
+    effect: &Effect,
+    input: &Buffer,
+    timestamps_ms: &[u32],
+) -> Vec<(Duration, Buffer)> {
+    let mut effect = effect.clone();
+    let area = input.area;
+
+    let mut last = Duration::ZERO;
+    timestamps_ms.iter()
+        .map(|&ms| Duration::from_millis(ms as u64))
+        .map(|timestamp| {
+            let mut buf = input.clone();
+            effect.process(timestamp.saturating_sub(last), &mut buf, area);
+            last = timestamp;
+
+            (timestamp, buf)
+        })
+        .collect()
+}
+
+/// Formats a buffer as its rows of text, delimited by `|`, followed by the style
+/// runs of each row.
+pub fn format_buffer(buf: &Buffer) -> String {
+    let area = buf.area;
+    let mut out = String::new();
+
+    for y in area.top()..area.bottom() {
+        let row: String = (area.left()..area.right())
+            .map(|x| buf.get(x, y).symbol())
+            .collect();
+        writeln!(out, "|{row}|").unwrap();
+    }
+
+    for y in area.top()..area.bottom() {
+        let mut x = area.left();
+        while x < area.right() {
+            let style = format_style(buf.get(x, y));
+            let start = x;
+            while x < area.right() && format_style(buf.get(x, y)) == style {
+                x += 1;
+            }
+            writeln!(out, "{y}: {start}..{x} {style}").unwrap();
+        }
+    }
+
+    out
+}
+
+/// Formats the frames returned by [`render_frames`], each headed by its timestamp.
+pub fn format_frames(frames: &[(Duration, Buffer)]) -> String {
+    frames.iter()
+        .map(|(timestamp, buf)| format!("--- {}ms ---\n{}", timestamp.as_millis(), format_buffer(buf)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders the effect at the given timestamps and compares the frames against the
+/// snapshot `tests/snapshots/{name}.snap`, relative to the manifest directory of the
+/// crate under test. See [`render_frames`].
+///
+/// # Arguments
+/// * `name` - The name of the snapshot.
+/// * `effect` - The effect to render.
+/// * `input` - The buffer the effect is applied to.
+/// * `timestamps_ms` - The timestamps of the frames, in milliseconds, in ascending order.
+///
+/// # Panics
+/// * If the rendered frames differ from the snapshot, or if the snapshot is missing.
+pub fn assert_snapshot(name: &str, effect: &Effect, input: &Buffer, timestamps_ms: &[u32]) {
+    let actual = format_frames(&render_frames(effect, input, timestamps_ms));
+    assert_text_snapshot(name, &actual);
+}
+
+/// Compares `actual` against the snapshot `tests/snapshots/{name}.snap`, relative to
+/// the manifest directory of the crate under test.
+///
+/// # Panics
+/// * If `actual` differs from the snapshot, or if the snapshot is missing.
+pub fn assert_text_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+
+    if std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|v| v == "1") {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = match std::fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(_) => panic!(
+            "missing snapshot {}; run with {UPDATE_SNAPSHOTS_ENV}=1 to create it",
+            path.display()
+        ),
+    };
+
+    if let Some(diff) = diff(&expected, actual) {
+        panic!(
+            "snapshot '{name}' does not match ({}); run with {UPDATE_SNAPSHOTS_ENV}=1 to update it\n\
+            --- expected\n+++ actual\n{diff}",
+            path.display()
+        );
+    }
+}
+
+/// Returns a line diff between `expected` and `actual`, or `None` if they are equal.
+/// Changed lines are prefixed with `-` and `+`, surrounded by up to three lines of
+/// unchanged context. Each hunk is headed by its line numbers in `expected` and `actual`.
+///
+/// # Example
+/// ```
+/// use tachyonfx::testing::diff;
+///
+/// assert_eq!(diff("a\nb\nc", "a\nB\nc"), Some("@@ -1 +1 @@\n a\n-b\n+B\n c\n".into()));
+/// assert_eq!(diff("a", "a"), None);
+/// ```
+pub fn diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    const CONTEXT: usize = 3;
+
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let edits = line_edits(&expected, &actual);
+
+    // line numbers of the expected and actual text, at each edit
+    let mut line_numbers = Vec::with_capacity(edits.len());
+    let (mut expected_line, mut actual_line) = (1, 1);
+    for edit in &edits {
+        line_numbers.push((expected_line, actual_line));
+        match edit {
+            Edit::Same(_)   => { expected_line += 1; actual_line += 1; },
+            Edit::Remove(_) => expected_line += 1,
+            Edit::Add(_)    => actual_line += 1,
+        }
+    }
+
+    let is_change = |i: usize| !matches!(edits[i], Edit::Same(_));
+    let mut out = String::new();
+    let mut last_printed: Option<usize> = None;
+    for i in 0..edits.len() {
+        let near_change = (i.saturating_sub(CONTEXT)..(i + CONTEXT + 1).min(edits.len()))
+            .any(is_change);
+        if !near_change {
+            continue;
+        }
+
+        if last_printed.map_or(true, |last| last + 1 < i) {
+            let (expected_line, actual_line) = line_numbers[i];
+            writeln!(out, "@@ -{expected_line} +{actual_line} @@").unwrap();
+        }
+        last_printed = Some(i);
+
+        match edits[i] {
+            Edit::Same(line)   => writeln!(out, " {line}"),
+            Edit::Remove(line) => writeln!(out, "-{line}"),
+            Edit::Add(line)    => writeln!(out, "+{line}"),
+        }.unwrap();
+    }
+
+    if out.is_empty() {
+        out.push_str("(the texts differ only in line endings)\n");
+    }
+
+    Some(out)
+}
+
+#[derive(Clone, Copy)]
+enum Edit<'a> {
+    Same(&'a str),
+    Remove(&'a str),
+    Add(&'a str),
+}
+
+/// Computes the edits turning `a` into `b`, from the longest common subsequence of lines.
+fn line_edits<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<Edit<'a>> {
+    let (n, m) = (a.len(), b.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = match a[i] == b[j] {
+                true  => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut edits = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && a[i] == b[j] {
+            edits.push(Edit::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            edits.push(Edit::Remove(a[i]));
+            i += 1;
+        } else {
+            edits.push(Edit::Add(b[j]));
+            j += 1;
+        }
+    }
+
+    edits
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("CARGO_MANIFEST_DIR is not set; snapshots must be run through cargo");
+
+    PathBuf::from(manifest_dir)
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.snap"))
+}
+
+fn format_style(cell: &Cell) -> String {
+    let mut style = format!("fg={} bg={}", format_color(cell.fg), format_color(cell.bg));
+    if cell.modifier != Modifier::empty() {
+        write!(style, " mod={:?}", cell.modifier).unwrap();
+    }
+
+    style
+}
+
+fn format_color(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        color               => format!("{color:?}"),
+    }
+}
@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use crate::effect_timer::{global_time_scale, scale_duration, EffectTimer};
+use crate::interpolation::{Interpolatable, Interpolation};
+use crate::keyframes::Keyframes;
+
+/// Animates a value of any [Interpolatable] type, such as a scroll offset, a gauge
+/// ratio or a color, from one value to another, or through a set of [Keyframes],
+/// over time. A `Tween` is ticked with
+/// the same duration as is passed to [render_effect](crate::EffectRenderer::render_effect),
+/// and is typically used to animate widget parameters before rendering.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use tachyonfx::{Interpolation, Tween};
+///
+/// let mut scroll: Tween<u16> = Tween::new(0, 20, (400, Interpolation::QuadOut));
+///
+/// scroll.process(Duration::from_millis(200));
+/// assert_eq!(scroll.value(), 15);
+/// assert_eq!(scroll.progress(), 0.5);
+///
+/// scroll.process(Duration::from_millis(200));
+/// assert_eq!(scroll.value(), 20);
+/// assert!(scroll.done());
+///
+/// // continue from the current value
+/// scroll.retarget(0);
+/// assert_eq!(scroll.value(), 20);
+/// ```
+#[derive(Clone)]
+pub struct Tween<T> {
+    path: TweenPath<T>,
+    timer: EffectTimer,
+}
+
+#[derive(Clone)]
+enum TweenPath<T> {
+    Range { from: T, to: T },
+    Keyframes(Keyframes<T>),
+}
+
+impl<T> Tween<T>
+    where T: Interpolatable<T> + Clone
+{
+    /// Creates a new `Tween` from `from` to `to`.
+    ///
+    /// # Arguments
+    /// * `from` - The initial value.
+    /// * `to` - The target value.
+    /// * `timer` - The duration and interpolation of the tween.
+    ///
+    /// # Returns
+    /// * A new `Tween` instance.
+    pub fn new<D: Into<EffectTimer>>(from: T, to: T, timer: D) -> Self {
+        Self { path: TweenPath::Range { from, to }, timer: timer.into() }
+    }
+
+    /// Creates a new `Tween` passing through the keyframes, lasting until the last stop.
+    ///
+    /// # Arguments
+    /// * `keyframes` - The keyframes of the tween.
+    ///
+    /// # Returns
+    /// * A new `Tween` instance.
+    ///
+    /// # Panics
+    /// * If `keyframes` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use tachyonfx::{Interpolation, Keyframes, Tween};
+    ///
+    /// let mut offset: Tween<u16> = Tween::keyframes(Keyframes::new()
+    ///     .stop(0, 0, Interpolation::Linear)
+    ///     .stop(100, 10, Interpolation::Linear)
+    ///     .stop(300, 0, Interpolation::Linear));
+    ///
+    /// offset.process(Duration::from_millis(200));
+    /// assert_eq!(offset.value(), 5);
+    /// assert_eq!(offset.to(), &0);
+    /// ```
+    pub fn keyframes(keyframes: Keyframes<T>) -> Self {
+        assert!(!keyframes.is_empty(), "keyframes must have at least one stop");
+
+        let timer = EffectTimer::new(keyframes.duration(), Interpolation::Linear);
+        Self { path: TweenPath::Keyframes(keyframes), timer }
+    }
+
+    /// Advances the tween by the specified duration, scaled by the
+    /// [global time scale](crate::global_time_scale).
+    ///
+    /// # Arguments
+    /// * `duration` - The duration to advance the tween by.
+    ///
+    /// # Returns
+    /// * An `Option` containing the overflow duration if the tween is done, or `None`
+    ///   if it is still running.
+    pub fn process(&mut self, duration: Duration) -> Option<Duration> {
+        self.timer.process(scale_duration(duration, global_time_scale()))
+    }
+
+    /// Returns the current value of the tween.
+    pub fn value(&self) -> T {
+        match &self.path {
+            TweenPath::Range { from, to }   => from.lerp(to, self.timer.alpha()),
+            TweenPath::Keyframes(keyframes) => keyframes.value_at(self.timer.alpha_elapsed()).unwrap(),
+        }
+    }
+
+    /// Returns the linear progress of the tween, from `0.0` to `1.0`, before
+    /// any interpolation is applied.
+    pub fn progress(&self) -> f32 {
+        let total = self.timer.duration().as_secs_f32();
+        match total {
+            0.0 => 1.0,
+            _   => self.timer.elapsed().as_secs_f32() / total,
+        }
+    }
+
+    /// Returns true if the tween has reached its target value.
+    pub fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    /// Returns true if the tween is still running.
+    pub fn running(&self) -> bool {
+        !self.done()
+    }
+
+    /// Returns the initial value of the tween; for keyframes, the value of the first stop.
+    pub fn from(&self) -> &T {
+        match &self.path {
+            TweenPath::Range { from, .. }   => from,
+            TweenPath::Keyframes(keyframes) => keyframes.first().unwrap(),
+        }
+    }
+
+    /// Returns the target value of the tween; for keyframes, the value of the last stop.
+    pub fn to(&self) -> &T {
+        match &self.path {
+            TweenPath::Range { to, .. }     => to,
+            TweenPath::Keyframes(keyframes) => keyframes.last().unwrap(),
+        }
+    }
+
+    /// Returns the timer of the tween.
+    pub fn timer(&self) -> &EffectTimer {
+        &self.timer
+    }
+
+    /// Restarts the tween from its initial value.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Jumps the tween to the specified elapsed time.
+    ///
+    /// # Arguments
+    /// * `elapsed` - The elapsed time to jump to.
+    pub fn seek(&mut self, elapsed: Duration) {
+        self.timer.set_elapsed(elapsed);
+    }
+
+    /// Restarts the tween from its current value towards a new target, e.g. when
+    /// the user scrolls again before the previous animation has completed. A tween
+    /// following keyframes continues with the timer's duration and interpolation.
+    ///
+    /// # Arguments
+    /// * `to` - The new target value.
+    pub fn retarget(&mut self, to: T) {
+        self.path = TweenPath::Range { from: self.value(), to };
+        self.timer.reset();
+    }
+}
+
+impl<T> From<Keyframes<T>> for Tween<T>
+    where T: Interpolatable<T> + Clone
+{
+    fn from(keyframes: Keyframes<T>) -> Self {
+        Tween::keyframes(keyframes)
+    }
+}
@@ -0,0 +1,262 @@
+//! Derive macros for [tachyonfx](https://docs.rs/tachyonfx). This crate is re-exported
+//! by tachyonfx and should not be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Ident, Member};
+
+/// Methods which can be implemented by hand, as inherent methods of the shader.
+const USER_METHODS: [&str; 6] = ["process", "execute", "done", "reverse", "duration", "seek"];
+
+/// Derives the `Shader` trait, generating the boilerplate methods from the fields
+/// marked with `#[shader(...)]`:
+///
+/// - `#[shader(timer)]` - an `EffectTimer`, used for `done`, `reverse`, `timer` and `timer_mut`
+/// - `#[shader(area)]` - an `Option<Rect>`, used for `area` and `set_area`
+/// - `#[shader(filter)]` - a `CellFilter`, used for `cell_selection` and `set_cell_selection`
+/// - `#[shader(color_space)]` - an `Option<ColorSpace>`, used for `set_color_space`
+/// - `#[shader(effect)]` - a wrapped child `Effect`, which receives every call not covered
+///   by any of the above
+///
+/// The remaining logic is written as inherent methods with the same signatures as their
+/// `Shader` counterparts, and listed in a `#[shader(...)]` attribute on the struct. If
+/// the attribute is omitted, only `execute` is expected. Valid methods are `process`,
+/// `execute`, `done`, `reverse`, `duration` and `seek`; if `process` is listed without
+/// `execute`, the generated `execute` does nothing. An expected method which is not
+/// implemented is reported as a compile error.
+///
+/// The shader must implement `Clone`.
+#[proc_macro_derive(Shader, attributes(shader))]
+pub fn derive_shader(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The fields marked with `#[shader(...)]`.
+#[derive(Default)]
+struct ShaderFields {
+    timer: Option<Member>,
+    area: Option<Member>,
+    filter: Option<Member>,
+    color_space: Option<Member>,
+    effect: Option<Member>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = parse_fields(&input)?;
+    let methods = parse_user_methods(&input)?;
+    let user = |name: &str| methods.iter().any(|m| m == name);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fx = quote! { ::tachyonfx };
+    let private = quote! { ::tachyonfx::__private };
+
+    let process = user("process").then(|| {
+        let body = call_inherent("process", quote! { self, duration, buf, area });
+        quote! {
+            fn process(
+                &mut self,
+                duration: #private::Duration,
+                buf: &mut #private::Buffer,
+                area: #private::Rect,
+            ) -> Option<#private::Duration> {
+                #body
+            }
+        }
+    });
+
+    let execute = match user("execute") || !user("process") {
+        true  => call_inherent("execute", quote! { self, alpha, area, cell_iter }),
+        false => quote! { let _ = (alpha, area, cell_iter); },
+    };
+
+    let done = match (&fields.timer, &fields.effect) {
+        _ if user("done") => call_inherent("done", quote! { self }),
+        (Some(timer), _)  => quote! { self.#timer.done() },
+        (_, Some(child))  => quote! { self.#child.done() },
+        _ => return Err(syn::Error::new_spanned(
+            name,
+            "`#[derive(Shader)]` requires a `#[shader(timer)]` or `#[shader(effect)]` field, \
+             or a `done` method listed in `#[shader(done)]`",
+        )),
+    };
+
+    let (area, set_area) = match (&fields.area, &fields.effect) {
+        (Some(area), _)  => (quote! { self.#area }, quote! { self.#area = Some(area); }),
+        (_, Some(child)) => (quote! { self.#child.area() }, quote! { self.#child.set_area(area); }),
+        _                => (quote! { None }, quote! { let _ = area; }),
+    };
+
+    let (cell_selection, set_cell_selection) = match (&fields.filter, &fields.effect) {
+        (Some(filter), _) => (
+            Some(quote! { Some(self.#filter.clone()) }),
+            quote! { self.#filter = filter; },
+        ),
+        (_, Some(child)) => (
+            Some(quote! { self.#child.cell_selection() }),
+            quote! { self.#child.set_cell_selection(filter); },
+        ),
+        _ => (None, quote! { let _ = filter; }),
+    };
+    let cell_selection = cell_selection.map(|body| quote! {
+        fn cell_selection(&self) -> Option<#fx::CellFilter> { #body }
+    });
+
+    let set_color_space = match (&fields.color_space, &fields.effect) {
+        (Some(color_space), _) => Some(quote! { self.#color_space = Some(color_space); }),
+        (_, Some(child))       => Some(quote! { self.#child.set_color_space(color_space); }),
+        _                      => None,
+    };
+    let set_color_space = set_color_space.map(|body| quote! {
+        fn set_color_space(&mut self, color_space: #fx::ColorSpace) { #body }
+    });
+
+    let reverse = match (&fields.timer, &fields.effect) {
+        _ if user("reverse") => Some(call_inherent("reverse", quote! { self })),
+        (Some(timer), _)     => Some(quote! { self.#timer = self.#timer.reversed(); }),
+        (_, Some(child))     => Some(quote! { self.#child.reverse(); }),
+        _                    => None,
+    };
+    let reverse = reverse.map(|body| quote! {
+        fn reverse(&mut self) { #body }
+    });
+
+    let timers = match (&fields.timer, &fields.effect) {
+        (Some(timer), _) => Some((quote! { Some(&self.#timer) }, quote! { Some(&mut self.#timer) })),
+        (_, Some(child)) => Some((quote! { self.#child.timer() }, quote! { self.#child.timer_mut() })),
+        _                => None,
+    };
+    let timers = timers.map(|(timer, timer_mut)| quote! {
+        fn timer(&self) -> Option<&#fx::EffectTimer> { #timer }
+        fn timer_mut(&mut self) -> Option<&mut #fx::EffectTimer> { #timer_mut }
+    });
+
+    let delegate_to_effect = fields.timer.is_none().then_some(fields.effect.as_ref()).flatten();
+
+    let duration = match delegate_to_effect {
+        _ if user("duration") => Some(call_inherent("duration", quote! { self })),
+        Some(child)           => Some(quote! { self.#child.duration() }),
+        None                  => None,
+    };
+    let duration = duration.map(|body| quote! {
+        fn duration(&self) -> Option<#private::Duration> { #body }
+    });
+
+    let seek = match delegate_to_effect {
+        _ if user("seek") => Some(call_inherent("seek", quote! { self, elapsed })),
+        Some(child)       => Some(quote! { self.#child.seek(elapsed) }),
+        None              => None,
+    };
+    let seek = seek.map(|body| quote! {
+        fn seek(&mut self, elapsed: #private::Duration) { #body }
+    });
+
+    let loop_count = fields.effect.as_ref().map(|child| quote! {
+        fn loop_count(&self) -> u32 { self.#child.loop_count() }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #fx::Shader for #name #ty_generics #where_clause {
+            #process
+
+            fn execute(
+                &mut self,
+                alpha: f32,
+                area: #private::Rect,
+                cell_iter: #fx::CellIterator,
+            ) {
+                #execute
+            }
+
+            fn done(&self) -> bool { #done }
+
+            fn clone_box(&self) -> Box<dyn #fx::Shader> {
+                Box::new(Clone::clone(self))
+            }
+
+            fn area(&self) -> Option<#private::Rect> { #area }
+
+            fn set_area(&mut self, area: #private::Rect) { #set_area }
+
+            fn set_cell_selection(&mut self, filter: #fx::CellFilter) { #set_cell_selection }
+
+            #cell_selection
+            #set_color_space
+            #reverse
+            #timers
+            #duration
+            #seek
+            #loop_count
+        }
+    })
+}
+
+/// Calls the inherent `method` of the shader. A local trait with a same-named, argument-less
+/// method turns a missing inherent method into a compile error; otherwise, the call would
+/// resolve to the `Shader` method being generated and recurse forever.
+fn call_inherent(method: &str, args: TokenStream2) -> TokenStream2 {
+    let method = Ident::new(method, Span::call_site());
+    quote! {{
+        #[allow(dead_code)]
+        trait InherentShaderMethod { fn #method(); }
+        impl<T: ?Sized> InherentShaderMethod for T { fn #method() {} }
+        <Self>::#method(#args)
+    }}
+}
+
+fn parse_fields(input: &DeriveInput) -> syn::Result<ShaderFields> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "`#[derive(Shader)]` only supports structs"));
+    };
+
+    let mut fields = ShaderFields::default();
+    for (idx, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None        => Member::Unnamed(idx.into()),
+        };
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("shader")) {
+            attr.parse_nested_meta(|meta| {
+                let kind = meta.path.get_ident().map(Ident::to_string).unwrap_or_default();
+                let slot = match kind.as_str() {
+                    "timer"       => &mut fields.timer,
+                    "area"        => &mut fields.area,
+                    "filter"      => &mut fields.filter,
+                    "color_space" => &mut fields.color_space,
+                    "effect"      => &mut fields.effect,
+                    _ => return Err(meta.error("expected one of `timer`, `area`, `filter`, `color_space` or `effect`")),
+                };
+
+                match slot.replace(member.clone()) {
+                    Some(_) => Err(meta.error(format!("only one field can be marked `#[shader({kind})]`"))),
+                    None    => Ok(()),
+                }
+            })?;
+        }
+    }
+
+    Ok(fields)
+}
+
+fn parse_user_methods(input: &DeriveInput) -> syn::Result<Vec<Ident>> {
+    let mut methods = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("shader")) {
+        attr.parse_nested_meta(|meta| {
+            match meta.path.get_ident() {
+                Some(ident) if USER_METHODS.contains(&ident.to_string().as_str()) => {
+                    methods.push(ident.clone());
+                    Ok(())
+                },
+                _ => Err(meta.error(format!("expected one of: {}", USER_METHODS.join(", ")))),
+            }
+        })?;
+    }
+
+    Ok(methods)
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use tachyonfx::{fx, CellFilter, Effect, IntoEffect, Shader};
+use tachyonfx::fx::RepeatMode;
+
+/// Wraps an effect, leaving every call but `process` to the generated delegation.
+#[derive(Clone, Shader)]
+#[shader(process)]
+struct Wrapper {
+    #[shader(effect)]
+    fx: Effect,
+}
+
+impl Wrapper {
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        self.fx.process(duration, buf, area)
+    }
+}
+
+fn wrap(fx: Effect) -> Effect {
+    Wrapper { fx }.into_effect()
+}
+
+#[test]
+fn delegates_to_effect_field() {
+    let mut effect = wrap(fx::fade_to_fg(Color::Red, 500));
+    assert_eq!(effect.duration(), Some(Duration::from_millis(500)));
+    assert_eq!(effect.timer().map(|t| t.duration()), Some(Duration::from_millis(500)));
+
+    let area = Rect::new(1, 1, 4, 2);
+    effect.set_area(area);
+    assert_eq!(effect.area(), Some(area));
+
+    effect.set_cell_selection(CellFilter::Text);
+    assert!(matches!(effect.cell_selection(), Some(CellFilter::Text)));
+
+    effect.seek(Duration::from_millis(200));
+    assert_eq!(effect.timer().map(|t| t.elapsed()), Some(Duration::from_millis(200)));
+    assert!(effect.running());
+
+    let mut reversed = fx::fade_to_fg(Color::Red, 500);
+    reversed.seek(Duration::from_millis(200));
+    reversed.reverse();
+    effect.reverse();
+    assert_eq!(effect.timer().map(|t| t.alpha()), reversed.timer().map(|t| t.alpha()));
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+    effect.process(Duration::from_millis(300), &mut buf, area);
+    assert!(effect.done());
+}
+
+#[test]
+fn delegates_loop_count_to_effect_field() {
+    let repeat = fx::repeat(fx::fade_to_fg(Color::Red, 100), RepeatMode::Forever);
+    let mut effect = wrap(repeat);
+
+    let area = Rect::new(0, 0, 4, 1);
+    let mut buf = Buffer::empty(area);
+    effect.process(Duration::from_millis(250), &mut buf, area);
+    assert_eq!(effect.loop_count(), 2);
+}
+
+#[test]
+fn derive_errors() {
+    trybuild::TestCases::new().compile_fail("tests/derive/*.rs");
+}
@@ -0,0 +1,16 @@
+use tachyonfx::{CellIterator, EffectTimer, Shader};
+use ratatui::layout::Rect;
+
+#[derive(Clone, Shader)]
+struct TwoTimers {
+    #[shader(timer)]
+    fade_in: EffectTimer,
+    #[shader(timer)]
+    fade_out: EffectTimer,
+}
+
+impl TwoTimers {
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+}
+
+fn main() {}
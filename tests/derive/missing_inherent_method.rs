@@ -0,0 +1,15 @@
+use tachyonfx::{CellIterator, EffectTimer, Shader};
+use ratatui::layout::Rect;
+
+#[derive(Clone, Shader)]
+#[shader(execute, done)]
+struct MissingDone {
+    #[shader(timer)]
+    lifetime: EffectTimer,
+}
+
+impl MissingDone {
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use tachyonfx::{CellIterator, Shader};
+use ratatui::layout::Rect;
+
+#[derive(Clone, Shader)]
+struct NoTimer {
+    strength: f32,
+}
+
+impl NoTimer {
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use tachyonfx::{CellIterator, EffectTimer, Shader};
+use ratatui::layout::Rect;
+
+#[derive(Clone, Shader)]
+struct Misspelled {
+    #[shader(time)]
+    lifetime: EffectTimer,
+}
+
+impl Misspelled {
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use tachyonfx::{CellIterator, EffectTimer, Shader};
+use ratatui::layout::Rect;
+
+#[derive(Clone, Shader)]
+#[shader(execute, render)]
+struct UnknownMethod {
+    #[shader(timer)]
+    lifetime: EffectTimer,
+}
+
+impl UnknownMethod {
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+}
+
+fn main() {}
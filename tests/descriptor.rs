@@ -0,0 +1,94 @@
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use tachyonfx::descriptor::{CellFilterDescriptor, EffectDescriptor, FxDescriptor, RepeatModeDescriptor, TimerDescriptor};
+use tachyonfx::{ColorSpace, Interpolation, Shader};
+use tachyonfx::fx::Direction;
+
+fn round_trip(descriptor: &EffectDescriptor) {
+    let json = serde_json::to_string(descriptor).unwrap();
+    let from_json: EffectDescriptor = serde_json::from_str(&json).unwrap();
+    assert_eq!(&from_json, descriptor, "{json}");
+}
+
+fn boxed(fx: FxDescriptor) -> Box<EffectDescriptor> {
+    Box::new(fx.into())
+}
+
+/// The window opening effect of the open-window example.
+fn open_window() -> EffectDescriptor {
+    let bg = Color::from_u32(0x1d2021);
+    let reversed = TimerDescriptor { reversed: true, ..TimerDescriptor::new(1200, Interpolation::QuartInOut) };
+
+    FxDescriptor::Parallel {
+        effects: vec![
+            FxDescriptor::PingPong {
+                effect: boxed(FxDescriptor::Translate { effect: None, translate_by: [0, -25], timer: reversed }),
+            }.into(),
+            FxDescriptor::Sequence {
+                effects: vec![
+                    FxDescriptor::WithDuration {
+                        duration_ms: 320,
+                        effect: boxed(FxDescriptor::NeverComplete {
+                            effect: boxed(FxDescriptor::FadeTo { fg: bg, bg, timer: 0.into() }),
+                        }),
+                    }.into(),
+                    FxDescriptor::FadeFrom { fg: bg, bg, timer: (320, Interpolation::QuadOut).into() }.into(),
+                ],
+            }.into(),
+            FxDescriptor::Repeat {
+                effect: boxed(FxDescriptor::Coalesce { cycle_len: 111, timer: (440, Interpolation::BounceOut).into(), seed: Some(7) }),
+                mode: RepeatModeDescriptor::Times(2),
+            }.into(),
+            EffectDescriptor::from(FxDescriptor::ResizeArea {
+                effect: Some(boxed(FxDescriptor::SweepIn {
+                    direction: Direction::LeftToRight,
+                    gradient_length: 10,
+                    color: bg,
+                    timer: 500.into(),
+                })),
+                initial_w: 10,
+                initial_h: 4,
+                timer: 300.into(),
+            }).with_time_scale(0.5),
+        ],
+    }.into()
+}
+
+#[test]
+fn nested_tree_round_trips_through_json() {
+    let filter = CellFilterDescriptor::AllOf(vec![
+        CellFilterDescriptor::Outer([1, 1]),
+        CellFilterDescriptor::Not(Box::new(CellFilterDescriptor::Text)),
+    ]);
+    let descriptor = open_window()
+        .with_cell_filter(filter)
+        .with_area(Rect::new(2, 1, 40, 12))
+        .with_time_scale(1.5)
+        .with_color_space(ColorSpace::Oklab);
+
+    round_trip(&descriptor);
+    assert!(descriptor.to_effect().running());
+}
+
+#[test]
+fn translate_and_resize_area_without_effect() {
+    round_trip(&FxDescriptor::Translate { effect: None, translate_by: [3, -2], timer: 200.into() }.into());
+    round_trip(&FxDescriptor::ResizeArea { effect: None, initial_w: 0, initial_h: 5, timer: 200.into() }.into());
+
+    let json = r#"{ "type": "translate", "translate_by": [0, -25], "timer": { "duration_ms": 1200 } }"#;
+    let descriptor: EffectDescriptor = serde_json::from_str(json).unwrap();
+    assert_eq!(descriptor.fx, FxDescriptor::Translate { effect: None, translate_by: [0, -25], timer: 1200.into() });
+}
+
+#[test]
+fn slides_round_trip() {
+    let fill = Color::Black;
+    let timer = TimerDescriptor::new(500, Interpolation::BackOut);
+    round_trip(&FxDescriptor::SlideIn { from: [-30, 0], fill, timer }.into());
+    round_trip(&FxDescriptor::SlideOut { to: [0, 8], fill, timer }.into());
+
+    let json = r##"{ "type": "slide_in", "from": [-30, 0], "fill": "Black", "timer": { "duration_ms": 500, "interpolation": "BackOut" } }"##;
+    let descriptor: EffectDescriptor = serde_json::from_str(json).unwrap();
+    assert_eq!(descriptor.fx, FxDescriptor::SlideIn { from: [-30, 0], fill, timer });
+}
@@ -0,0 +1,59 @@
+use tachyonfx::dsl::{parse_effect, ParseError};
+
+fn parse_err(input: &str) -> ParseError {
+    parse_effect(input).err().expect("expected a parse error")
+}
+
+#[test]
+fn durations_too_large_for_duration() {
+    let huge = "1".repeat(40);
+
+    let err = parse_err(&format!("fade_to(Red, Red, {huge}ms)"));
+    assert_eq!((err.line, err.column), (1, 19));
+    assert_eq!(err.message, "duration is too large");
+
+    let err = parse_err(&format!("fade_to(Red, Red,\n  {huge}s QuadOut)"));
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.message, "duration is too large");
+}
+
+#[test]
+fn timers_too_large_for_duration() {
+    let err = parse_err(&format!("fade_to(Red, Red, {} QuadOut)", "9".repeat(40)));
+    assert_eq!((err.line, err.column), (1, 19));
+    assert_eq!(err.message, "duration is too large");
+}
+
+#[test]
+fn large_durations_still_parse() {
+    assert!(parse_effect("fade_to(Red, Red, 1000000000s)").is_ok());
+    assert!(parse_effect("fade_to(Red, Red, 1000000000000 QuadOut)").is_ok());
+}
+
+#[test]
+fn negative_durations_and_timers() {
+    let err = parse_err("fade_to(Red, Red, -500 QuadOut)");
+    assert_eq!((err.line, err.column), (1, 19));
+    assert_eq!(err.message, "durations cannot be negative");
+
+    let err = parse_err("sequence(\n  fade_to(Red, Red, -1.5s))");
+    assert_eq!((err.line, err.column), (2, 21));
+    assert_eq!(err.message, "durations cannot be negative");
+}
+
+#[test]
+fn offsets_must_be_integers_in_range() {
+    let err = parse_err("translate(None, [1.7, 0], 100)");
+    assert_eq!((err.line, err.column), (1, 18));
+    assert_eq!(err.message, "expected integer, found 1.7");
+
+    let err = parse_err("slide_in([0, 99999], Black, 100)");
+    assert_eq!((err.line, err.column), (1, 14));
+    assert_eq!(err.message, "number 99999 is out of range");
+
+    let err = parse_err("slide_out([1, 2, 3], Black, 100)");
+    assert_eq!((err.line, err.column), (1, 11));
+    assert_eq!(err.message, "expected list of 2 integers, found 3 element(s)");
+
+    assert!(parse_effect("translate(None, [-3, 2], 100)").is_ok());
+}
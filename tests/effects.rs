@@ -0,0 +1,127 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use tachyonfx::{fx, Pattern, Shader};
+use tachyonfx::fx::RepeatMode;
+
+#[test]
+fn translate_outlasted_by_child() {
+    let fade = fx::fade_to_fg(Color::Red, 800);
+    let mut effect = fx::translate(Some(fade), (2, 1), 300);
+    assert_eq!(effect.duration(), Some(Duration::from_millis(800)));
+
+    let area = Rect::new(0, 0, 10, 4);
+    let mut buf = Buffer::empty(area);
+
+    effect.seek(Duration::from_millis(500));
+    effect.process(Duration::ZERO, &mut buf, area);
+    assert!(!effect.done());
+
+    effect.seek(Duration::from_millis(800));
+    effect.process(Duration::ZERO, &mut buf, area);
+    assert!(effect.done());
+}
+
+#[test]
+fn time_scale_clamped_to_valid_range() {
+    let area = Rect::new(0, 0, 10, 4);
+    let mut buf = Buffer::empty(area);
+
+    let mut effect = fx::dissolve(10, 500).with_time_scale(f32::INFINITY);
+    assert_eq!(effect.time_scale(), 1000.0);
+    effect.process(Duration::MAX, &mut buf, area);
+    assert!(effect.done());
+
+    effect.set_time_scale(f32::NAN);
+    assert_eq!(effect.time_scale(), 1000.0);
+
+    effect.set_time_scale(-1.0);
+    assert_eq!(effect.time_scale(), 0.0);
+
+    let mut effect = fx::dissolve(10, 500).with_time_scale(f32::MIN_POSITIVE);
+    assert_eq!(effect.duration(), Some(Duration::MAX));
+    effect.process(Duration::from_millis(100), &mut buf, area);
+    assert!(effect.running());
+}
+
+#[test]
+fn repeat_seek_matches_process() {
+    let area = Rect::new(0, 0, 10, 4);
+    let frame = Duration::from_millis(50);
+    let modes = [
+        RepeatMode::Forever,
+        RepeatMode::Times(3),
+        RepeatMode::Duration(Duration::from_millis(500)),
+    ];
+
+    for mode in modes {
+        let repeat = || fx::repeat(fx::fade_to_fg(Color::Red, 200), mode.clone());
+        let mut processed = repeat();
+
+        for frames in 1..=16 {
+            let mut processed_buf = Buffer::empty(area);
+            processed.process(frame, &mut processed_buf, area);
+
+            let mut seeked = repeat();
+            let mut seeked_buf = Buffer::empty(area);
+            seeked.seek(frame * frames);
+            seeked.process(Duration::ZERO, &mut seeked_buf, area);
+
+            let at = format!("{mode:?} at {:?}", frame * frames);
+            assert_eq!(seeked.loop_count(), processed.loop_count(), "loop count, {at}");
+            assert_eq!(seeked.done(), processed.done(), "done, {at}");
+            assert_eq!(seeked_buf, processed_buf, "buffer, {at}");
+        }
+    }
+}
+
+#[test]
+fn patterned_effect_fires_hooks_once() {
+    let area = Rect::new(0, 0, 10, 4);
+    let mut buf = Buffer::empty(area);
+
+    let started = Rc::new(Cell::new(0));
+    let completed = Rc::new(Cell::new(0));
+    let fade = {
+        let (started, completed) = (started.clone(), completed.clone());
+        fx::fade_to_fg(Color::Red, 500)
+            .on_start(move || started.set(started.get() + 1))
+            .on_complete(move || completed.set(completed.get() + 1))
+    };
+    let mut effect = fx::with_pattern(Pattern::radial((0.5, 0.5)), fade);
+
+    for _ in 0..3 {
+        effect.process(Duration::from_millis(50), &mut buf, area);
+    }
+    assert_eq!((started.get(), completed.get()), (1, 0));
+
+    while effect.running() {
+        effect.process(Duration::from_millis(50), &mut buf, area);
+    }
+    assert_eq!((started.get(), completed.get()), (1, 1));
+}
+
+#[test]
+fn seek_progress_with_invalid_progress() {
+    let area = Rect::new(0, 0, 10, 4);
+    let mut buf = Buffer::empty(area);
+
+    let mut effect = fx::fade_to_fg(Color::Red, 500);
+    effect.seek_progress(f32::NAN);
+    effect.process(Duration::ZERO, &mut buf, area);
+    assert_eq!(effect.timer().unwrap().alpha(), 0.0);
+
+    effect.seek_progress(f32::INFINITY);
+    effect.process(Duration::ZERO, &mut buf, area);
+    assert!(effect.done());
+
+    // the duration saturates at Duration::MAX
+    let mut endless = fx::dissolve(10, 500).with_time_scale(f32::MIN_POSITIVE);
+    endless.seek_progress(1.0);
+    endless.seek_progress(0.5);
+}
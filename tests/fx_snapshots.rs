@@ -0,0 +1,545 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect};
+use ratatui::style::{Color, Style};
+
+use tachyonfx::{fx, CellFilter, ColorDepth, ColorSpace, Effect, Gradient, Interpolation, Keyframes, Pattern, StepPosition};
+use tachyonfx::fx::{Direction, Emitter, Glitch, MatrixRain, NoiseField, NoiseKind, NoiseTarget, ParticleSystem, RepeatMode, Shatter, SweepShape, Typewriter};
+use tachyonfx::testing::assert_snapshot;
+
+const DARK0: Color = Color::Rgb(0x1d, 0x20, 0x21);
+const LIGHT2: Color = Color::Rgb(0xd5, 0xc4, 0xa1);
+const RED: Color = Color::Rgb(0xfb, 0x49, 0x34);
+
+const TIMESTAMPS: [u32; 5] = [0, 100, 250, 400, 500];
+
+fn input() -> Buffer {
+    let mut buf = Buffer::with_lines([
+        "tachyon",
+        "  fx   ",
+        "0.1.0 !",
+    ]);
+    buf.set_style(buf.area, Style::default().fg(LIGHT2).bg(DARK0));
+    buf.set_style(Rect::new(2, 1, 2, 1), Style::default().fg(RED));
+    buf
+}
+
+fn previous_screen() -> Buffer {
+    let mut buf = Buffer::with_lines([
+        "<- back",
+        "#######",
+        "page  2",
+    ]);
+    buf.set_style(buf.area, Style::default().fg(RED).bg(Color::Black));
+    buf
+}
+
+fn named_colors() -> Buffer {
+    let mut buf = Buffer::with_lines([
+        "tachyon",
+        "  fx   ",
+        "0.1.0 !",
+    ]);
+    buf.set_style(Rect::new(0, 0, 7, 1), Style::default().fg(Color::Red).bg(Color::Reset));
+    buf.set_style(Rect::new(0, 1, 7, 1), Style::default().fg(Color::Indexed(33)).bg(Color::Blue));
+    buf.set_style(Rect::new(0, 2, 7, 1), Style::default().fg(Color::Reset).bg(Color::Indexed(236)));
+    buf
+}
+
+fn assert_fx(name: &str, effect: Effect) {
+    assert_snapshot(name, &effect, &input(), &TIMESTAMPS);
+}
+
+#[test]
+fn fade_to() {
+    assert_fx("fade_to", fx::fade_to(RED, Color::Black, 500));
+}
+
+#[test]
+fn fade_from() {
+    assert_fx("fade_from", fx::fade_from(Color::Black, Color::Black, (500, Interpolation::QuadOut)));
+}
+
+#[test]
+fn fade_to_fg() {
+    assert_fx("fade_to_fg", fx::fade_to_fg(DARK0, (500, Interpolation::SineIn)));
+}
+
+#[test]
+fn fade_from_fg() {
+    assert_fx("fade_from_fg", fx::fade_from_fg(DARK0, 500));
+}
+
+#[test]
+fn fade_cubic_interpolations() {
+    let timestamps = [0, 100, 200, 300, 400, 500];
+    let input = input();
+    [Interpolation::CubicIn, Interpolation::CubicOut, Interpolation::CubicInOut].iter()
+        .for_each(|&interpolation| {
+            let name = format!("fade_to_fg_{interpolation:?}").to_lowercase();
+            let effect = fx::fade_to_fg(Color::Black, (500, interpolation));
+            assert_snapshot(&name, &effect, &input, &timestamps);
+        });
+}
+
+#[test]
+fn fade_custom_interpolations() {
+    let timestamps = [0, 100, 200, 300, 400, 500];
+    let input = input();
+    [
+        ("cubic_bezier", Interpolation::CubicBezier(0.68, -0.55, 0.265, 1.55)),
+        ("steps", Interpolation::Steps(3, StepPosition::JumpEnd)),
+        ("spring", Interpolation::Spring { stiffness: 100.0, damping: 12.0 }),
+        ("custom", Interpolation::Custom(|a| a * a)),
+    ].iter().for_each(|&(name, interpolation)| {
+        let effect = fx::fade_to_fg(Color::Black, (500, interpolation));
+        assert_snapshot(&format!("fade_to_fg_{name}"), &effect, &input, &timestamps);
+    });
+}
+
+#[test]
+fn fade_color_spaces() {
+    let blue = Color::Rgb(0x45, 0x85, 0x88);
+    [ColorSpace::LinearRgb, ColorSpace::Hsl, ColorSpace::Oklab, ColorSpace::Oklch].iter()
+        .for_each(|&space| {
+            let name = format!("fade_to_{space:?}").to_lowercase();
+            assert_fx(&name, fx::fade_to(blue, LIGHT2, 500).with_color_space(space));
+        });
+}
+
+#[test]
+fn fade_named_colors() {
+    let effect = fx::fade_to(LIGHT2, DARK0, 500);
+    assert_snapshot("fade_to_named_colors", &effect, &named_colors(), &TIMESTAMPS);
+}
+
+#[test]
+fn sweep_in_color_space() {
+    let effect = fx::sweep_in(Direction::LeftToRight, 3, RED, 500)
+        .with_color_space(ColorSpace::Oklch);
+    assert_fx("sweep_in_oklch", effect);
+}
+
+#[test]
+fn hsl_shift_color_space() {
+    let effect = fx::hsl_shift(Some([120.0, 0.0, 0.0]), Some([0.0, -50.0, 20.0]), 500)
+        .with_color_space(ColorSpace::Oklab);
+    assert_fx("hsl_shift_oklab", effect);
+}
+
+#[test]
+fn sweep_in() {
+    assert_fx("sweep_in_left_to_right", fx::sweep_in(Direction::LeftToRight, 3, DARK0, 500));
+    assert_fx("sweep_in_down_to_up", fx::sweep_in(Direction::DownToUp, 2, DARK0, 500));
+}
+
+#[test]
+fn sweep_in_shapes() {
+    let center = (0.5, 0.5);
+    for (name, shape) in [
+        ("sweep_in_linear_45", SweepShape::Linear { angle: 45.0 }),
+        ("sweep_in_iris_open", SweepShape::IrisOpen { center }),
+        ("sweep_in_clockwise", SweepShape::Clockwise { center, start_angle: -90.0 }),
+    ] {
+        assert_fx(name, fx::sweep_in_shape(shape, 4, DARK0, 500));
+    }
+}
+
+#[test]
+fn sweep_out_iris_close() {
+    let shape = SweepShape::IrisClose { center: (0.5, 0.5) };
+    assert_fx("sweep_out_iris_close", fx::sweep_out_shape(shape, 4, DARK0, 500));
+}
+
+#[test]
+fn sweep_in_gradient() {
+    let gradient = Gradient::linear(90.0)
+        .stop(0.0, DARK0)
+        .stop(1.0, RED);
+    assert_fx("sweep_in_gradient", fx::sweep_in_gradient(Direction::LeftToRight, 3, gradient, 500));
+}
+
+#[test]
+fn fade_to_gradient() {
+    let rainbow = Gradient::linear(0.0)
+        .stop(0.0, RED)
+        .stop(0.5, LIGHT2)
+        .stop(1.0, Color::Rgb(0x45, 0x85, 0x88));
+    let glow = Gradient::radial((0.5, 0.5))
+        .stop(0.0, LIGHT2)
+        .stop(1.0, DARK0);
+    assert_fx("fade_to_gradient", fx::fade_to_gradient(Some(rainbow), Some(glow), 500));
+
+    let wheel = Gradient::angular((0.5, 0.5), 0.0)
+        .stop(0.0, RED)
+        .stop(0.5, Color::Rgb(0x45, 0x85, 0x88))
+        .stop(1.0, RED);
+    let effect = fx::fade_from_gradient(None, Some(wheel), 500);
+    assert_fx("fade_from_gradient_angular", effect);
+}
+
+#[test]
+fn sweep_out() {
+    assert_fx("sweep_out_right_to_left", fx::sweep_out(Direction::RightToLeft, 3, DARK0, 500));
+    assert_fx("sweep_out_up_to_down", fx::sweep_out(Direction::UpToDown, 2, DARK0, 500));
+}
+
+#[test]
+fn dissolve() {
+    assert_fx("dissolve", fx::dissolve_seeded(7, 3, 500));
+}
+
+#[test]
+fn coalesce() {
+    assert_fx("coalesce", fx::coalesce_seeded(7, 3, 500));
+}
+
+#[test]
+fn typewriter() {
+    assert_fx("typewriter", fx::typewriter(500));
+    assert_fx("typewriter_reversed", fx::typewriter(500).reversed());
+}
+
+#[test]
+fn typewriter_caret() {
+    let effect: Effect = Typewriter::builder()
+        .caret('▌')
+        .caret_blink(Duration::from_millis(200))
+        .jitter(0.5)
+        .seed(7)
+        .lifetime(500.into())
+        .into();
+
+    assert_fx("typewriter_caret", effect);
+}
+
+#[test]
+fn hsl_shift() {
+    assert_fx("hsl_shift", fx::hsl_shift(Some([120.0, 0.0, 0.0]), Some([0.0, -50.0, 20.0]), 500));
+    assert_fx("hsl_shift_fg", fx::hsl_shift_fg([-90.0, 20.0, -10.0], 500));
+}
+
+#[test]
+fn fade_keyframes() {
+    let fg = Keyframes::new()
+        .stop(100, RED, Interpolation::Linear)
+        .stop(250, RED, Interpolation::Linear)
+        .stop(500, Color::Black, Interpolation::QuadIn);
+    let bg = Keyframes::new()
+        .stop(250, LIGHT2, Interpolation::SineOut)
+        .stop(400, DARK0, Interpolation::Linear);
+
+    assert_fx("fade_fg_keyframes", fx::fade_fg_keyframes(fg.clone()));
+    assert_fx("fade_keyframes", fx::fade_keyframes(Some(fg), Some(bg)));
+}
+
+#[test]
+fn hsl_shift_keyframes() {
+    let fg = Keyframes::new()
+        .stop(100, [120.0, 0.0, 20.0], Interpolation::QuadOut)
+        .stop(500, [0.0, -50.0, 0.0], Interpolation::Linear);
+
+    assert_fx("hsl_shift_keyframes", fx::hsl_shift_keyframes(Some(fg), None));
+}
+
+#[test]
+fn patterns() {
+    for (name, pattern) in [
+        ("pattern_linear", Pattern::linear(45.0)),
+        ("pattern_radial", Pattern::radial((0.5, 0.5))),
+        ("pattern_diamond", Pattern::diamond((0.5, 0.5)).with_transition(0.0)),
+        ("pattern_checkerboard", Pattern::checkerboard(1)),
+        ("pattern_blinds", Pattern::blinds(2, false)),
+        ("pattern_noise", Pattern::noise(3.0, 7)),
+        ("pattern_random", Pattern::random(7).with_transition(0.5)),
+    ] {
+        assert_fx(name, fx::with_pattern(pattern, fx::fade_to(DARK0, RED, 500)));
+    }
+}
+
+#[test]
+fn dissolve_pattern() {
+    let effect = fx::with_pattern(Pattern::radial((0.0, 0.0)), fx::dissolve_seeded(1, 0, 500));
+    assert_fx("dissolve_pattern", effect);
+}
+
+#[test]
+fn hsl_shift_pattern() {
+    let effect = fx::hsl_shift_fg([180.0, 0.0, 0.0], 500);
+    assert_fx("hsl_shift_pattern", fx::with_pattern(Pattern::blinds(3, true), effect));
+}
+
+#[test]
+fn noise_fields() {
+    for (name, effect) in [
+        ("fire", fx::fire(7, 500)),
+        ("plasma", fx::plasma(7, 500)),
+        ("clouds", fx::clouds(7, 500)),
+    ] {
+        assert_fx(name, effect);
+    }
+}
+
+#[test]
+fn fire_empty_cells() {
+    let effect = fx::fire(7, 500).with_cell_selection(CellFilter::Empty);
+    assert_fx("fire_empty_cells", effect);
+}
+
+#[test]
+fn clouds_glyphs() {
+    let clouds: Effect = NoiseField::builder()
+        .kind(NoiseKind::Clouds)
+        .target(NoiseTarget::Glyphs)
+        .scale(4.0)
+        .speed(4.0)
+        .seed(7)
+        .lifetime(500.into())
+        .into();
+
+    assert_fx("clouds_glyphs", clouds);
+}
+
+#[test]
+fn matrix_rain() {
+    let rain: Effect = MatrixRain::builder()
+        .glyphs("01")
+        .speed(10.0..20.0)
+        .trail_length(2..4)
+        .density(0.6)
+        .seed(7)
+        .into();
+
+    assert_fx("matrix_rain", rain);
+}
+
+#[test]
+fn matrix_rain_behind_text() {
+    assert_fx("matrix_rain_empty_cells", fx::matrix_rain(7).with_cell_selection(CellFilter::Empty));
+}
+
+#[test]
+fn matrix_rain_over_default_background() {
+    // trails fade towards the terminal's default background
+    let input = Buffer::with_lines([
+        "tachyon",
+        "  fx   ",
+        "0.1.0 !",
+    ]);
+    assert_snapshot("matrix_rain_reset_bg", &fx::matrix_rain(7), &input, &TIMESTAMPS);
+}
+
+fn particle_burst() -> Effect {
+    let explosion = Emitter::builder()
+        .origin((0.5, 0.5))
+        .burst(12)
+        .speed(4.0..12.0)
+        .drag(1.5)
+        .lifetime_ms(200..450)
+        .glyphs("*+.")
+        .colors(vec![Color::White, Color::Rgb(0xfa, 0xbd, 0x2f), RED]);
+
+    ParticleSystem::builder()
+        .emitter(explosion)
+        .seed(7)
+        .into()
+}
+
+#[test]
+fn particles() {
+    assert_fx("particles_burst", particle_burst());
+}
+
+#[test]
+fn particles_color_space() {
+    assert_fx("particles_burst_oklch", particle_burst().with_color_space(ColorSpace::Oklch));
+}
+
+#[test]
+fn particles_fountain() {
+    let fountain = Emitter::builder()
+        .origin((0.5, 1.0))
+        .rate(40.0)
+        .duration(Duration::from_millis(250))
+        .angle(-90.0)
+        .angle_spread(30.0)
+        .speed(12.0..16.0)
+        .gravity(40.0)
+        .lifetime_ms(250..250);
+
+    let effect: Effect = ParticleSystem::builder()
+        .emitter(fountain)
+        .seed(7)
+        .into();
+
+    assert_fx("particles_fountain", effect);
+}
+
+#[test]
+fn explode() {
+    let timestamps = [0, 25, 50, 100, 200];
+    assert_snapshot("explode", &fx::explode(7, 200), &input(), &timestamps);
+    assert_snapshot("assemble", &fx::assemble(7, 200), &input(), &timestamps);
+}
+
+#[test]
+fn shatter_falling() {
+    let effect: Effect = Shatter::builder()
+        .speed(0.0..2.0)
+        .gravity(40.0)
+        .seed(7)
+        .lifetime(500.into())
+        .cell_filter(CellFilter::FgColor(RED))
+        .into();
+
+    assert_fx("shatter_falling", effect);
+}
+
+#[test]
+fn glitch() {
+    let glitch: Effect = Glitch::builder()
+        .cell_glitch_ratio(0.5)
+        .action_start_delay_ms(0..200)
+        .action_ms(50..150)
+        .seed(11)
+        .into();
+
+    assert_fx("glitch", glitch);
+}
+
+#[test]
+fn term256_colors() {
+    assert_snapshot("term256_colors", &fx::term256_colors(), &input(), &[0]);
+}
+
+#[test]
+fn quantize_colors() {
+    for (name, depth) in [
+        ("quantize_ansi256", ColorDepth::Ansi256),
+        ("quantize_ansi16", ColorDepth::Ansi16),
+        ("quantize_mono", ColorDepth::Mono),
+    ] {
+        assert_snapshot(name, &fx::quantize_colors(depth, false), &input(), &[0]);
+    }
+
+    let effect = fx::quantize_colors(ColorDepth::Ansi16, false);
+    assert_snapshot("quantize_ansi16_named_colors", &effect, &named_colors(), &[0]);
+}
+
+#[test]
+fn sweep_in_dithered() {
+    let sweep = fx::sweep_in(Direction::LeftToRight, 5, Color::Black, 500);
+    let effect = fx::parallel(vec![sweep, fx::quantize_colors(ColorDepth::Ansi16, true)]);
+    assert_fx("sweep_in_dithered_ansi16", effect);
+}
+
+#[test]
+fn translate() {
+    assert_fx("translate", fx::translate(Some(fx::fade_to_fg(RED, 500)), (2, 1), 500));
+}
+
+#[test]
+fn slide() {
+    let fill = Style::default().bg(Color::Black);
+    assert_fx("slide_in", fx::slide_in((-4, 0), fill, 500));
+    assert_fx("slide_in_back_out", fx::slide_in((-12, 0), fill, (500, Interpolation::BackOut)));
+    assert_fx("slide_out", fx::slide_out((3, -1), fill, 500));
+}
+
+#[test]
+fn slide_in_fading() {
+    let effect = fx::parallel(vec![
+        fx::slide_in((-4, 0), Style::default(), 500),
+        fx::fade_from_fg(DARK0, 500),
+    ]);
+    assert_fx("slide_in_fading", effect);
+}
+
+#[test]
+fn resize_area() {
+    assert_fx("resize_area", fx::resize_area(Some(fx::fade_from_fg(RED, 500)), 1, 1, 500));
+}
+
+#[test]
+fn sequence() {
+    assert_fx("sequence", fx::sequence(vec![
+        fx::fade_to_fg(RED, 200),
+        fx::sleep(100),
+        fx::dissolve_seeded(7, 5, 200),
+    ]));
+}
+
+#[test]
+fn parallel() {
+    assert_fx("parallel", fx::parallel(vec![
+        fx::fade_to(DARK0, LIGHT2, 500),
+        fx::coalesce_seeded(7, 5, 300),
+    ]));
+}
+
+#[test]
+fn repeat() {
+    assert_fx("repeat", fx::repeat(fx::fade_to_fg(RED, 200), RepeatMode::Times(2)));
+}
+
+#[test]
+fn ping_pong() {
+    assert_fx("ping_pong", fx::ping_pong(fx::fade_to_fg(RED, 250)));
+}
+
+#[test]
+fn never_complete() {
+    assert_fx("never_complete", fx::never_complete(fx::fade_to_fg(RED, 200)));
+}
+
+#[test]
+fn timed_never_complete() {
+    let effect = fx::timed_never_complete(Duration::from_millis(300), fx::fade_to(RED, RED, 200));
+    assert_fx("timed_never_complete", effect);
+}
+
+#[test]
+fn consume_tick() {
+    assert_fx("consume_tick", fx::sequence(vec![fx::consume_tick(), fx::fade_to_fg(RED, 400)]));
+}
+
+#[test]
+fn cell_selection() {
+    let effect = fx::fade_to(RED, Color::Black, 500)
+        .with_cell_selection(CellFilter::AllOf(vec![
+            CellFilter::Inner(Margin::new(1, 0)),
+            CellFilter::Not(CellFilter::FgColor(RED).into()),
+        ]));
+
+    assert_fx("cell_selection", effect);
+}
+
+#[test]
+fn cross_fade() {
+    assert_fx("cross_fade", fx::cross_fade(previous_screen(), 500));
+}
+
+#[test]
+fn cross_fade_color_space() {
+    let effect = fx::cross_fade(previous_screen(), 500).with_color_space(ColorSpace::Oklch);
+    assert_fx("cross_fade_oklch", effect);
+}
+
+#[test]
+fn dissolve_transition() {
+    assert_fx("dissolve_transition", fx::dissolve_transition_seeded(previous_screen(), 7, 17, 500));
+}
+
+#[test]
+fn slide_transition() {
+    assert_fx("slide_transition_left_to_right", fx::slide_transition(previous_screen(), Direction::LeftToRight, 500));
+    assert_fx("slide_transition_down_to_up", fx::slide_transition(previous_screen(), Direction::DownToUp, 500));
+}
+
+#[test]
+fn slide_transition_overshoot() {
+    let timestamps = [0, 100, 200, 300, 400, 500];
+    let effect = fx::slide_transition(previous_screen(), Direction::RightToLeft, (500, Interpolation::BackOut));
+    assert_snapshot("slide_transition_overshoot", &effect, &input(), &timestamps);
+}
@@ -0,0 +1,46 @@
+//! Tests mutating the global color depth; kept in their own test binary, as the
+//! color depth is shared by every test running in the same process.
+
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use tachyonfx::{fx, quantize_to_global_depth, set_global_color_depth, ColorDepth, EffectManager, EffectRenderer};
+
+const TRUE_COLOR: Color = Color::Rgb(0x12, 0x34, 0x56);
+
+fn true_color_buffer(area: Rect) -> Buffer {
+    let mut buf = Buffer::empty(area);
+    buf.set_style(area, TRUE_COLOR);
+    buf
+}
+
+#[test]
+fn quantization_is_a_final_pass_over_the_rendered_area() {
+    let area = Rect::new(0, 0, 10, 2);
+    let fade = || fx::fade_to_fg(Color::Rgb(250, 120, 30), 500)
+        .with_area(Rect::new(0, 0, 5, 1));
+
+    set_global_color_depth(ColorDepth::Ansi256);
+
+    // process_effects quantizes the areas of its effects, leaving other cells alone
+    let mut buf = true_color_buffer(area);
+    let mut effects: EffectManager<()> = EffectManager::default();
+    effects.add_effect(fade());
+    effects.process_effects(Duration::from_millis(100), &mut buf, area);
+    assert!(matches!(buf.get(4, 0).fg, Color::Indexed(_)));
+    assert_eq!(buf.get(5, 0).fg, TRUE_COLOR);
+    assert_eq!(buf.get(0, 1).fg, TRUE_COLOR);
+
+    // render_effect leaves the quantization to the final pass
+    let mut buf = true_color_buffer(area);
+    buf.render_effect(&mut fade(), area, Duration::from_millis(100));
+    assert!(matches!(buf.get(4, 0).fg, Color::Rgb(..)));
+
+    quantize_to_global_depth(&mut buf, area);
+    set_global_color_depth(ColorDepth::TrueColor);
+    assert!(matches!(buf.get(4, 0).fg, Color::Indexed(_)));
+    assert!(matches!(buf.get(5, 0).fg, Color::Indexed(_)));
+}
@@ -0,0 +1,27 @@
+//! Tests mutating the global time scale; kept in their own test binary, as the
+//! time scale is shared by every test running in the same process.
+
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use tachyonfx::{fx, set_global_time_scale, EffectRenderer, Shader};
+
+#[test]
+fn global_time_scale_applied_once_to_nested_effects() {
+    let area = Rect::new(0, 0, 10, 4);
+    let mut buf = Buffer::empty(area);
+
+    let fade = fx::fade_to_fg(Color::Red, 400);
+    let mut effect = fx::resize_area(Some(fade), 2, 2, 100);
+
+    set_global_time_scale(2.0);
+    buf.render_effect(&mut effect, area, Duration::from_millis(100));
+    assert!(effect.running());
+
+    buf.render_effect(&mut effect, area, Duration::from_millis(100));
+    set_global_time_scale(1.0);
+    assert!(effect.done());
+}